@@ -0,0 +1,18 @@
+#![allow(unused_imports)]
+use spinoff::{spinners, Color};
+use std::time::Duration;
+
+#[cfg(all(feature = "tokio", feature = "dots"))]
+#[tokio::main]
+async fn main() {
+    let mut sp = spinoff::AsyncSpinner::new(spinners::Dots, "Loading...", Color::Blue);
+    tokio::time::sleep(Duration::from_secs(3)).await;
+    sp.update_text("Still loading...");
+    tokio::time::sleep(Duration::from_secs(3)).await;
+    sp.success("Done!").await;
+}
+
+#[cfg(not(all(feature = "tokio", feature = "dots")))]
+fn main() {
+    println!("This example requires the 'tokio' and 'dots' features to be enabled.");
+}
@@ -0,0 +1,23 @@
+#![allow(unused_imports)]
+use spinoff::{spinners, Color, MultiSpinner};
+use std::{thread::sleep, time::Duration};
+
+#[cfg(feature = "dots")]
+fn main() {
+    let mut multi = MultiSpinner::new();
+    let download = multi.add(spinners::Dots, "Downloading...", Color::Blue);
+    let compile = multi.add(spinners::Dots, "Compiling...", Color::Yellow);
+    let test = multi.add(spinners::Dots, "Testing...", Color::Magenta);
+    sleep(Duration::from_secs(2));
+    download.success("Downloaded!");
+    sleep(Duration::from_secs(2));
+    compile.success("Compiled!");
+    sleep(Duration::from_secs(2));
+    test.fail("Tests failed!");
+    multi.stop();
+}
+
+#[cfg(not(feature = "dots"))]
+fn main() {
+    println!("This example requires the 'dots' feature to be enabled.");
+}
@@ -0,0 +1,381 @@
+use std::borrow::Cow;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::task::JoinHandle;
+use tokio::time::{self, Instant, Interval, MissedTickBehavior};
+
+use crate::handle::Handle;
+use crate::spinners::SpinnerFrames;
+use crate::state::{Finish, Status};
+use crate::utils::lock;
+use crate::{Color, ColorPolicy, DropBehavior, Output, Routing, Style, Symbols, Template};
+
+/// Terminal spinner driven by a tokio task instead of an OS thread.
+///
+/// It has the same methods as [`Spinner`](crate::Spinner), which document them in full,
+/// but the ones that stop it are `async` and never block the executor. Dropping it, e.g. because the task owning it was cancelled,
+/// stops it right away according to its [`DropBehavior`].
+pub struct AsyncSpinner {
+    task: Option<JoinHandle<()>>,
+    /// The state shared with the render task, and the `stop` type methods.
+    handle: Handle,
+}
+
+impl AsyncSpinner {
+    /**
+    Create a new async spinner.
+
+    # Arguments
+
+    * `spinner_type` - The spinner to use.
+    * `msg` - The message to display.
+    * `color` - The color of the spinner.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::time::Duration;
+    #
+    # #[tokio::main]
+    # async fn main() {
+    let mut sp = AsyncSpinner::new(spinners::Dots, "Hello World!", Color::Blue);
+    tokio::time::sleep(Duration::from_millis(800)).await;
+    sp.stop().await;
+    # }
+    ```
+
+    # Notes
+
+    * The spinner immediately starts spinning upon creation.
+    * This function outputs to the `stdout` stream. If you want to use a different stream, use the [`AsyncSpinner::new_with_stream`] function.
+
+    # Panics
+
+    * Panics if called outside of a tokio runtime.
+    */
+    pub fn new<S, T, U>(spinner_type: S, msg: T, color: U) -> Self
+    where
+        S: Into<SpinnerFrames>,
+        T: Into<Cow<'static, str>>,
+        U: Into<Option<Color>>,
+    {
+        Self::new_with_stream(spinner_type, msg, color, Output::default())
+    }
+
+    /**
+    Create a new async spinner outputting to a specific stream.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::time::Duration;
+    #
+    # #[tokio::main]
+    # async fn main() {
+    let mut sp = AsyncSpinner::new_with_stream(spinners::Dots, "I'm outputting to stderr!", Color::Yellow, Streams::Stderr);
+    tokio::time::sleep(Duration::from_millis(800)).await;
+    sp.clear().await;
+    # }
+    ```
+
+    # Notes
+
+    * If the stream is not a terminal (see [`Output::is_terminal`]), no task is spawned.
+      Instead, the message and every change to it are printed on their own lines.
+
+    # Panics
+
+    * Panics if called outside of a tokio runtime.
+    * The render task panics if the stream cannot be flushed.
+    */
+    pub fn new_with_stream<S, T, U, O>(spinner_type: S, msg: T, color: U, stream: O) -> Self
+    where
+        S: Into<SpinnerFrames>,
+        T: Into<Cow<'static, str>>,
+        U: Into<Option<Color>>,
+        O: Into<Output>,
+    {
+        // The cursor is hidden before the task can draw its first frame.
+        let handle = Handle::new(spinner_type.into(), msg.into(), color.into(), stream.into());
+        let task = handle.is_animated().then(|| {
+            tokio::spawn({
+                let still_spinning = Arc::clone(&handle.still_spinning);
+                let state = Arc::clone(&handle.state);
+                let stream = handle.stream.clone();
+                async move {
+                    let mut period = lock(&state).interval();
+                    let mut ticker = ticker(period);
+                    loop {
+                        ticker.tick().await;
+                        let mut state = lock(&state);
+                        if !still_spinning.load(Ordering::Relaxed) {
+                            break;
+                        }
+                        state.draw(&stream);
+                        // Show the drawn frame for as long as it asks for. Frames may have their
+                        // own durations, or have been replaced by ones with a different interval.
+                        if state.interval() != period {
+                            period = state.interval();
+                            ticker = ticker_at(Instant::now() + period, period);
+                        }
+                        state.advance();
+                    }
+                }
+            })
+        });
+        Self { task, handle }
+    }
+
+    /// Stops the spinner and keeps its message, see [`Spinner::stop`](crate::Spinner::stop).
+    pub async fn stop(&mut self) {
+        self.finish(Finish::Stop).await;
+    }
+
+    /// Stops the spinner and prints a message on a new line, see [`Spinner::stop_with_message`](crate::Spinner::stop_with_message).
+    pub async fn stop_with_message(&mut self, msg: &str) {
+        self.finish(Finish::Message(msg)).await;
+    }
+
+    /// Replaces the spinner with a symbol and a message, see [`Spinner::stop_and_persist`](crate::Spinner::stop_and_persist).
+    pub async fn stop_and_persist(&mut self, symbol: &str, msg: &str) {
+        self.finish(Finish::Persist(symbol, msg)).await;
+    }
+
+    /**
+    Replaces the spinner with a success symbol and a message, see [`Spinner::success`](crate::Spinner::success).
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::time::Duration;
+    #
+    # #[tokio::main]
+    # async fn main() {
+    let mut sp = AsyncSpinner::new(spinners::Aesthetic, "Trying to load information...", None);
+    tokio::time::sleep(Duration::from_millis(800)).await;
+    sp.success("Success!").await;
+    # }
+    ```
+    */
+    pub async fn success(&mut self, msg: &str) {
+        self.finish(Finish::Outcome(Status::Success, msg)).await;
+    }
+
+    /// Replaces the spinner with a failure symbol and a message, see [`Spinner::fail`](crate::Spinner::fail).
+    pub async fn fail(&mut self, msg: &str) {
+        self.finish(Finish::Outcome(Status::Fail, msg)).await;
+    }
+
+    /// Replaces the spinner with a warning symbol and a message, see [`Spinner::warn`](crate::Spinner::warn).
+    pub async fn warn(&mut self, msg: &str) {
+        self.finish(Finish::Outcome(Status::Warn, msg)).await;
+    }
+
+    /// Replaces the spinner with an info symbol and a message, see [`Spinner::info`](crate::Spinner::info).
+    pub async fn info(&mut self, msg: &str) {
+        self.finish(Finish::Outcome(Status::Info, msg)).await;
+    }
+
+    /// Deletes the spinner, see [`Spinner::clear`](crate::Spinner::clear).
+    pub async fn clear(&mut self) {
+        self.finish(Finish::Clear).await;
+    }
+
+    /// Replaces the frames, message and color, see [`Spinner::update`](crate::Spinner::update).
+    pub fn update<S, T, U>(&mut self, spinner: S, msg: T, color: U)
+    where
+        S: Into<SpinnerFrames>,
+        T: Into<Cow<'static, str>>,
+        U: Into<Option<Color>>,
+    {
+        self.handle
+            .change(|state, _| state.update(spinner.into(), msg.into(), color.into()));
+    }
+
+    /// Replaces the message, see [`Spinner::update_text`](crate::Spinner::update_text).
+    pub fn update_text<T>(&mut self, msg: T)
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        self.handle.change(|state, _| state.msg = msg.into());
+    }
+
+    /**
+    Updates the spinner text after a certain amount of time has passed, without blocking the executor.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::time::Duration;
+    #
+    # #[tokio::main]
+    # async fn main() {
+    let mut sp = AsyncSpinner::new(spinners::Arc, "Loading...", Color::Blue);
+    sp.update_after_time("Not Done Yet...", Duration::from_millis(800)).await;
+    tokio::time::sleep(Duration::from_millis(800)).await;
+    sp.success("Done!").await;
+    # }
+    ```
+    */
+    pub async fn update_after_time<T>(&mut self, updated_msg: T, duration: Duration)
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        time::sleep(duration).await;
+        self.update_text(updated_msg);
+    }
+
+    /// Prints a line above the spinner, see [`Spinner::println`](crate::Spinner::println).
+    pub fn println(&self, msg: &str) {
+        self.handle.println(msg);
+    }
+
+    /// Removes the spinner from the screen while `f` runs, see [`Spinner::suspend`](crate::Spinner::suspend).
+    ///
+    /// The render task waits until `f` returns, so `f` should not block for long.
+    pub fn suspend<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        self.handle.suspend(f)
+    }
+
+    /// Sets the total amount of work, see [`Spinner::set_total`](crate::Spinner::set_total).
+    pub fn set_total<T>(&mut self, total: T)
+    where
+        T: Into<Option<u64>>,
+    {
+        self.handle.change(|state, _| state.total = total.into());
+    }
+
+    /// Sets the amount of work done so far, see [`Spinner::set_position`](crate::Spinner::set_position).
+    pub fn set_position(&mut self, position: u64) {
+        self.handle.state().position = position;
+    }
+
+    /// Adds `delta` to the amount of work done so far. See [`AsyncSpinner::set_total`].
+    pub fn inc(&mut self, delta: u64) {
+        let mut state = self.handle.state();
+        state.position = state.position.saturating_add(delta);
+    }
+
+    /// Returns the amount of work done so far.
+    #[must_use]
+    pub fn position(&self) -> u64 {
+        self.handle.state().position
+    }
+
+    /// Shows how long the spinner has been running, see [`Spinner::show_elapsed`](crate::Spinner::show_elapsed).
+    pub fn show_elapsed(&mut self, show: bool) {
+        self.handle.change(|state, _| state.show_elapsed = show);
+    }
+
+    /// Lays out the spinner line with a [`Template`], see [`Spinner::set_template`](crate::Spinner::set_template).
+    pub fn set_template<T: Into<Template>>(&mut self, template: T) {
+        self.handle
+            .change(|state, _| state.template = Some(template.into()));
+    }
+
+    /// Sets the [`Style`] of the frame, see [`Spinner::set_frame_style`](crate::Spinner::set_frame_style).
+    pub fn set_frame_style(&mut self, style: Style) {
+        self.handle.change(|state, _| state.frame_style = style);
+    }
+
+    /// Sets the [`Style`] of the message, see [`Spinner::set_message_style`](crate::Spinner::set_message_style).
+    pub fn set_message_style(&mut self, style: Style) {
+        self.handle.change(|state, _| state.msg_style = style);
+    }
+
+    /// Sets the [`Style`] of the symbols, see [`Spinner::set_symbol_style`](crate::Spinner::set_symbol_style).
+    pub fn set_symbol_style(&mut self, style: Style) {
+        self.handle.state().symbol_style = style;
+    }
+
+    /// Sets the symbols printed by the `success` type methods, see [`Spinner::set_symbols`](crate::Spinner::set_symbols).
+    pub fn set_symbols(&mut self, symbols: Symbols) {
+        self.handle.state().symbols = symbols;
+    }
+
+    /// Sets the streams the `success` type methods print to, see [`Spinner::set_routing`](crate::Spinner::set_routing).
+    pub fn set_routing(&mut self, routing: Routing) {
+        self.handle.state().routing = routing;
+    }
+
+    /// Sets whether colors and styles are printed, see [`Spinner::set_color_policy`](crate::Spinner::set_color_policy).
+    pub fn set_color_policy(&mut self, policy: ColorPolicy) {
+        self.handle
+            .change(|state, stream| state.set_color_policy(policy, stream));
+    }
+
+    /// Returns the time since the spinner was created.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.handle.state().started.elapsed()
+    }
+
+    /**
+    Sets what the spinner does when it is dropped while still spinning. By default, it is cleared.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::time::Duration;
+    #
+    # #[tokio::main]
+    # async fn main() {
+    let task = tokio::spawn(async {
+        let mut sp = AsyncSpinner::new(spinners::Dots, "Waiting forever...", None);
+        sp.set_drop_behavior(DropBehavior::Persist);
+        std::future::pending::<()>().await;
+    });
+    tokio::time::sleep(Duration::from_millis(800)).await;
+    // Cancelling the task drops the spinner, which leaves "Waiting forever..." on the screen.
+    task.abort();
+    # }
+    ```
+    */
+    pub fn set_drop_behavior(&mut self, behavior: DropBehavior) {
+        self.handle.drop_behavior = behavior;
+    }
+
+    /// Stop the spinner, print what is left in its place, and wait for the render task to finish.
+    async fn finish(&mut self, finish: Finish<'_>) {
+        self.handle.finish(finish);
+        if let Some(task) = self.task.take() {
+            task.abort();
+            // The only possible error is the cancellation caused by the abort.
+            let _ = task.await;
+        }
+    }
+}
+
+impl Drop for AsyncSpinner {
+    /// Stops the spinner according to its [`DropBehavior`] if none of the `stop` type methods were called.
+    /// This never blocks, so it is safe when the task owning the spinner is cancelled.
+    fn drop(&mut self) {
+        self.handle.finish_dropped();
+        if let Some(task) = &self.task {
+            task.abort();
+        }
+    }
+}
+
+/// An interval ticking every `period`, starting right away.
+fn ticker(period: Duration) -> Interval {
+    ticker_at(Instant::now(), period)
+}
+
+/// An interval ticking every `period`, starting at `start`.
+/// Ticks missed because the runtime was busy are skipped rather than drawn in a burst.
+fn ticker_at(start: Instant, period: Duration) -> Interval {
+    let mut ticker = time::interval_at(start, period);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    ticker
+}
@@ -0,0 +1,114 @@
+use std::io::Write;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use crate::utils::lock;
+use crate::Output;
+
+/// Outputs that currently have their cursor hidden by a spinner.
+static HIDDEN: Mutex<Vec<(u64, Output)>> = Mutex::new(Vec::new());
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Hides the cursor of a stream for as long as it is alive.
+///
+/// Render threads hold one of these, so the cursor comes back however the thread ends,
+/// including when it unwinds from a panic.
+pub(crate) struct HiddenCursor {
+    id: u64,
+    stream: Output,
+}
+
+impl HiddenCursor {
+    pub fn hide(stream: &Output) -> Self {
+        #[cfg(all(feature = "signals", any(unix, windows)))]
+        install_signal_handler();
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        lock(&HIDDEN).push((id, stream.clone()));
+        write!(stream, "\x1b[?25l");
+        Self {
+            id,
+            stream: stream.clone(),
+        }
+    }
+}
+
+impl Drop for HiddenCursor {
+    fn drop(&mut self) {
+        lock(&HIDDEN).retain(|(id, _)| *id != self.id);
+        write!(self.stream, "\x1b[?25h");
+        // Nothing else to do if this fails, and panicking in drop would abort.
+        let _ = self.stream.get_stream().flush();
+    }
+}
+
+/**
+Clears the current line and shows the cursor again on every stream a spinner is running on.
+
+The spinners restore the terminal themselves when they stop, are dropped or unwind from a panic.
+This function is for the cases where that can't happen, such as a signal handler that exits
+the process while a spinner is still running.
+
+# Example
+
+```
+# use spinoff::*;
+# use std::thread::sleep;
+# use std::time::Duration;
+#
+let _sp = Spinner::new(spinners::Dots, "Waiting for Ctrl-C...", None);
+sleep(Duration::from_millis(800));
+// In a signal handler, right before exiting:
+spinoff::restore_terminal();
+```
+
+# Notes
+
+* With the `signals` feature, which is enabled by default, `spinoff` handles `SIGINT` and `SIGTERM`
+  from the moment the first spinner starts animating: it calls this function and exits with status 130 or 143.
+  If your application handles signals itself, e.g. to shut down gracefully, disable the feature
+  and call this function from your handler.
+* On Windows, the feature handles Ctrl-C with the `ctrlc` crate, which only allows one handler per
+  process, so an application can't install its own `ctrlc` handler once a spinner has started.
+*/
+pub fn restore_terminal() {
+    for (_, stream) in lock(&HIDDEN).iter() {
+        write!(stream, "\r\x1b[K\x1b[?25h");
+        let _ = stream.get_stream().flush();
+    }
+}
+
+/// Restore the terminal and exit when the process is interrupted or terminated, with the usual
+/// status of 128 plus the signal number. The handler is installed the first time a cursor is hidden.
+#[cfg(all(feature = "signals", unix))]
+fn install_signal_handler() {
+    use signal_hook::consts::{SIGINT, SIGTERM};
+    use signal_hook::iterator::Signals;
+
+    static INSTALL: std::sync::Once = std::sync::Once::new();
+    INSTALL.call_once(|| {
+        // Without a handler the signals keep their default behavior, which still ends the process.
+        let Ok(mut signals) = Signals::new([SIGINT, SIGTERM]) else {
+            return;
+        };
+        std::thread::spawn(move || {
+            if let Some(signal) = signals.forever().next() {
+                restore_terminal();
+                std::process::exit(128 + signal);
+            }
+        });
+    });
+}
+
+/// Restore the terminal and exit when Ctrl-C is pressed or the console is closed.
+/// The handler is installed the first time a cursor is hidden.
+#[cfg(all(feature = "signals", windows))]
+fn install_signal_handler() {
+    static INSTALL: std::sync::Once = std::sync::Once::new();
+    INSTALL.call_once(|| {
+        // Fails if the application already installed a `ctrlc` handler, which then stays in charge.
+        let _ = ctrlc::set_handler(|| {
+            restore_terminal();
+            std::process::exit(130);
+        });
+    });
+}
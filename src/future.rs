@@ -0,0 +1,212 @@
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use crate::spinners::SpinnerFrames;
+use crate::Spinner;
+
+/**
+Adds [`with_spinner`](SpinFuture::with_spinner) to every future, to show a spinner while it is pending.
+
+# Example
+
+```
+# use spinoff::*;
+# use std::time::Duration;
+#
+async fn fetch() -> Result<String, std::io::Error> {
+    tokio::time::sleep(Duration::from_millis(800)).await;
+    Ok("data".to_string())
+}
+
+# #[tokio::main]
+# async fn main() {
+let data = fetch().with_spinner(spinners::Dots, "Fetching").await;
+assert!(data.is_ok());
+# }
+```
+*/
+pub trait SpinFuture: Future + Sized {
+    /**
+    Shows a spinner with `msg` until the future resolves, then prints a success line with `msg` if it returns `Ok`,
+    or a failure line with the error if it returns `Err`. Futures with other outputs, such as `()`, numbers, strings,
+    `Option` or `Vec`, print the success line.
+
+    # Notes
+
+    * The spinner starts when the future is first polled, and is drawn by its own thread, so any executor will do.
+    * If the future is dropped before it resolves, e.g. because it timed out, the spinner is cleared.
+    * The spinner's thread is woken up to stop it, so printing the final line doesn't wait for the current frame to end.
+    */
+    fn with_spinner<S, M>(self, spinner_type: S, msg: M) -> WithSpinner<Self>
+    where
+        Self::Output: Outcome,
+        S: Into<SpinnerFrames>,
+        M: Into<Cow<'static, str>>,
+    {
+        WithSpinner {
+            future: self,
+            frames: Some(spinner_type.into()),
+            msg: msg.into(),
+            spinner: None,
+        }
+    }
+}
+
+impl<F: Future> SpinFuture for F {}
+
+mod outcome {
+    use crate::Spinner;
+
+    /// The outputs [`with_spinner`](super::SpinFuture::with_spinner) knows how to finish the spinner for.
+    pub trait Outcome {
+        /// Print the line left behind by the spinner once the future resolved to this output.
+        fn finish(&self, spinner: &mut Spinner, msg: &str);
+    }
+}
+
+use outcome::Outcome;
+
+impl<T, E: Display> Outcome for Result<T, E> {
+    fn finish(&self, spinner: &mut Spinner, msg: &str) {
+        match self {
+            Ok(_) => spinner.success(msg),
+            Err(err) => spinner.fail(&err.to_string()),
+        }
+    }
+}
+
+/// Outputs that carry no success or failure, so the spinner always succeeds.
+macro_rules! succeed {
+    ( $( $ty:ty ),* $(,)? ) => {
+        $(
+            impl Outcome for $ty {
+                fn finish(&self, spinner: &mut Spinner, msg: &str) {
+                    spinner.success(msg);
+                }
+            }
+        )*
+    };
+}
+
+succeed! {
+    (), bool, char, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64,
+    String, &str,
+}
+
+impl<T> Outcome for Option<T> {
+    fn finish(&self, spinner: &mut Spinner, msg: &str) {
+        spinner.success(msg);
+    }
+}
+
+impl<T> Outcome for Vec<T> {
+    fn finish(&self, spinner: &mut Spinner, msg: &str) {
+        spinner.success(msg);
+    }
+}
+
+/// A future that shows a spinner while it is pending, see [`SpinFuture`].
+pub struct WithSpinner<F> {
+    /// Pinned along with `WithSpinner`, see `poll`.
+    future: F,
+    /// The frames of the spinner, until it is started on the first poll.
+    frames: Option<SpinnerFrames>,
+    msg: Cow<'static, str>,
+    /// Cleared when dropped while still spinning, i.e. when the future is dropped before it resolves.
+    spinner: Option<Spinner>,
+}
+
+impl<F> Future for WithSpinner<F>
+where
+    F: Future,
+    F::Output: Outcome,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: `future` is never moved out of `WithSpinner`, which has no `Drop` impl and is only
+        // `Unpin` if `F` is, so it stays pinned. The other fields are not pinned and may be moved.
+        let this = unsafe { self.get_unchecked_mut() };
+        if let Some(frames) = this.frames.take() {
+            this.spinner = Some(Spinner::new(frames, this.msg.clone(), None));
+        }
+        // SAFETY: see above.
+        let future = unsafe { Pin::new_unchecked(&mut this.future) };
+        let Poll::Ready(output) = future.poll(cx) else {
+            return Poll::Pending;
+        };
+        if let Some(mut spinner) = this.spinner.take() {
+            output.finish(&mut spinner, &this.msg);
+        }
+        Poll::Ready(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::task::{Wake, Waker};
+
+    use super::*;
+
+    struct Noop;
+
+    impl Wake for Noop {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    /// Poll `future` until it resolves, without an executor.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let waker = Waker::from(Arc::new(Noop));
+        let mut cx = Context::from_waker(&waker);
+        let mut future = Box::pin(future);
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    /// A future that is pending once. Like every `async fn`, it isn't `Unpin`.
+    async fn pending_once<T>(output: T) -> T {
+        let mut polled = false;
+        std::future::poll_fn(|_| {
+            if std::mem::replace(&mut polled, true) {
+                Poll::Ready(())
+            } else {
+                Poll::Pending
+            }
+        })
+        .await;
+        output
+    }
+
+    fn frames() -> SpinnerFrames {
+        SpinnerFrames::new(["-"], 100)
+    }
+
+    #[test]
+    fn plain_outputs_are_returned() {
+        block_on(pending_once(()).with_spinner(frames(), "Working"));
+        assert_eq!(
+            block_on(pending_once(42).with_spinner(frames(), "Working")),
+            42
+        );
+        assert_eq!(
+            block_on(pending_once(Some("a")).with_spinner(frames(), "Working")),
+            Some("a")
+        );
+    }
+
+    #[test]
+    fn results_are_returned() {
+        let ok: Result<u8, &str> = block_on(pending_once(Ok(1)).with_spinner(frames(), "Working"));
+        assert_eq!(ok, Ok(1));
+        let err: Result<u8, &str> =
+            block_on(pending_once(Err("failed")).with_spinner(frames(), "Working"));
+        assert_eq!(err, Err("failed"));
+    }
+}
@@ -0,0 +1,117 @@
+use std::borrow::Cow;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::cursor::HiddenCursor;
+use crate::spinners::SpinnerFrames;
+use crate::state::{Finish, SpinnerState};
+use crate::utils::lock;
+use crate::{Color, DropBehavior, Output};
+
+/// Everything a spinner does besides driving its animation, shared by [`Spinner`](crate::Spinner)
+/// and `AsyncSpinner`, which only add a render thread or task.
+pub(crate) struct Handle {
+    /// The frames, message and color, read by the driver on every tick.
+    pub state: Arc<Mutex<SpinnerState>>,
+    pub stream: Output,
+    /// Cleared by the `stop` type methods. The driver reads it while holding the state lock,
+    /// so nothing is drawn once it is cleared.
+    pub still_spinning: Arc<AtomicBool>,
+    pub drop_behavior: DropBehavior,
+    /// Hidden for as long as the spinner is animated.
+    cursor: Option<HiddenCursor>,
+}
+
+impl Handle {
+    /// Set up a spinner. On a terminal the cursor is hidden, ready for the driver to animate it,
+    /// otherwise there is nothing to animate and the message is printed right away.
+    pub fn new(
+        frames: SpinnerFrames,
+        msg: Cow<'static, str>,
+        color: Option<Color>,
+        stream: Output,
+    ) -> Self {
+        let mut state = SpinnerState::new(frames, msg, color, &stream);
+        let cursor = if state.animated {
+            Some(HiddenCursor::hide(&stream))
+        } else {
+            state.draw(&stream);
+            None
+        };
+        Self {
+            state: Arc::new(Mutex::new(state)),
+            stream,
+            still_spinning: Arc::new(AtomicBool::new(true)),
+            drop_behavior: DropBehavior::default(),
+            cursor,
+        }
+    }
+
+    /// Whether the spinner needs a driver, i.e. it is drawn on a terminal.
+    pub fn is_animated(&self) -> bool {
+        self.state().animated
+    }
+
+    /// Whether the spinner is still running, i.e. none of the `stop` type methods were called yet.
+    pub fn is_spinning(&self) -> bool {
+        self.still_spinning.load(Ordering::Relaxed)
+    }
+
+    /// The state, for changes that show up on the next frame.
+    pub fn state(&self) -> MutexGuard<'_, SpinnerState> {
+        lock(&self.state)
+    }
+
+    /// Change the state and show the change right away instead of waiting for the next frame.
+    pub fn change(&self, f: impl FnOnce(&mut SpinnerState, &Output)) {
+        let mut state = self.state();
+        f(&mut state, &self.stream);
+        if self.is_spinning() {
+            state.draw(&self.stream);
+        }
+    }
+
+    /// Run `f` with the spinner removed from the screen, then draw it again below whatever `f` printed.
+    pub fn suspend<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        // Holding the lock keeps the driver from drawing in between.
+        let mut state = self.state();
+        let spinning = self.is_spinning();
+        if spinning {
+            state.clear(&self.stream);
+        }
+        let result = f();
+        if spinning {
+            state.draw(&self.stream);
+        }
+        result
+    }
+
+    pub fn println(&self, msg: &str) {
+        self.suspend(|| writeln!(self.stream, "{msg}"));
+    }
+
+    /// Stop drawing and print what is left in place of the spinner. The driver is told to stop,
+    /// but not waited for, so this never blocks on it.
+    pub fn finish(&mut self, finish: Finish<'_>) {
+        let mut state = lock(&self.state);
+        let was_spinning = self.still_spinning.swap(false, Ordering::Relaxed);
+        assert!(
+            was_spinning,
+            "Stopping the spinner should only happen once."
+        );
+        state.clear(&self.stream);
+        // Show the cursor again.
+        self.cursor = None;
+        state.finish(finish, &self.stream);
+    }
+
+    /// Stop the spinner according to its [`DropBehavior`] if none of the `stop` type methods were called.
+    pub fn finish_dropped(&mut self) {
+        if self.is_spinning() {
+            self.finish(Finish::dropped(self.drop_behavior));
+        }
+    }
+}
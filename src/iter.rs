@@ -0,0 +1,127 @@
+use std::borrow::Cow;
+
+use crate::spinners::SpinnerFrames;
+use crate::Spinner;
+
+/**
+Adds [`spin`](SpinIterator::spin) to every iterator, to show a spinner while iterating.
+
+# Example
+
+```
+# use spinoff::*;
+# use std::thread::sleep;
+# use std::time::Duration;
+#
+let files = ["a.txt", "b.txt", "c.txt"];
+for file in files.iter().spin("Processing") {
+    sleep(Duration::from_millis(300));
+}
+```
+*/
+pub trait SpinIterator: Iterator + Sized {
+    /**
+    Shows a spinner with `msg` and the number of items so far, e.g. `Processing 12`, or `Processing 12/40`
+    if the length of the iterator is known. A success line is printed once the iterator is exhausted.
+
+    The spinner is [`Dots`](crate::spinners::Dots), or a plain line spinner if the `dots` feature is disabled.
+
+    # Notes
+
+    * If the iterator is dropped before it is exhausted, the spinner is cleared.
+    */
+    fn spin<T>(self, msg: T) -> Spin<Self>
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        self.spin_with(default_frames(), msg)
+    }
+
+    /**
+    Like [`spin`](SpinIterator::spin), with a spinner of your choice.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let total: u32 = (1..=5)
+        .spin_with(spinners::Line, "Adding")
+        .inspect(|_| sleep(Duration::from_millis(200)))
+        .sum();
+    assert_eq!(total, 15);
+    ```
+    */
+    fn spin_with<S, T>(self, spinner_type: S, msg: T) -> Spin<Self>
+    where
+        S: Into<SpinnerFrames>,
+        T: Into<Cow<'static, str>>,
+    {
+        Spin::new(self, spinner_type.into(), msg.into())
+    }
+}
+
+impl<I: Iterator> SpinIterator for I {}
+
+/// The frames of [`SpinIterator::spin`].
+#[cfg(feature = "dots")]
+fn default_frames() -> SpinnerFrames {
+    crate::spinners::Dots.into()
+}
+
+/// The frames of [`SpinIterator::spin`], a line spinner as the `dots` feature is disabled.
+#[cfg(not(feature = "dots"))]
+fn default_frames() -> SpinnerFrames {
+    SpinnerFrames::new(["-", "\\", "|", "/"], 100)
+}
+
+/// An iterator that shows a spinner while it is iterated, see [`SpinIterator`].
+pub struct Spin<I> {
+    iter: I,
+    spinner: Spinner,
+    msg: Cow<'static, str>,
+    finished: bool,
+}
+
+impl<I: Iterator> Spin<I> {
+    fn new(iter: I, frames: SpinnerFrames, msg: Cow<'static, str>) -> Self {
+        let mut spinner = Spinner::new(frames, msg.clone(), None);
+        // The count is the spinner's position, which is cheap to update for every item.
+        match iter.size_hint() {
+            (len, Some(max)) if len == max => {
+                spinner.set_template("{spinner} {msg} {pos}/{total}");
+                spinner.set_total(len as u64);
+            }
+            _ => spinner.set_template("{spinner} {msg} {pos}"),
+        }
+        Self {
+            iter,
+            spinner,
+            msg,
+            finished: false,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for Spin<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next();
+        if item.is_some() {
+            self.spinner.inc(1);
+        } else if !self.finished {
+            self.finished = true;
+            self.spinner.success(&self.msg);
+        }
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for Spin<I> {}
@@ -40,42 +40,145 @@ sp.success("Success!");
 ### Colors
 
 You can also color your spinners without any hassle. Simply pass a color to the `color` option.
-There are 9 colors available: blue, green, red, yellow, cyan, white, magenta, black and a custom variant.
+There are the 8 basic colors (blue, green, red, yellow, cyan, white, magenta and black), their bright variants,
+the 256 colors of [`Color::Ansi256`] and a custom variant.
+The animated [`Color::Gradient`], [`Color::Rainbow`] and [`Color::Pulse`] change color with every frame.
 Don't want any of that? Simply pass `None` to the `color` option.
 
-### Note
+For background colors, bold, dim, italic or underlined text, set a [`Style`] for the frame,
+the message or the status symbols:
 
-Currently, the library is designed in a way that doesn't support using multiple spinners at a time. However, that may change in the future.
+```
+# use spinoff::*;
+# use std::thread::sleep;
+# use std::time::Duration;
+#
+let mut sp = Spinner::new(spinners::Dots, "Loading...", Color::BrightMagenta);
+sp.set_message_style(Style::new().bold().on(Color::Ansi256(236)));
+sleep(Duration::from_millis(800));
+sp.success("Success!");
+```
+
+Colors are only printed to terminals, and follow the `NO_COLOR`, `CLICOLOR` and `CLICOLOR_FORCE` environment variables.
+Use [`set_default_color_policy`] or [`Spinner::set_color_policy`] to turn them on or off regardless.
+
+### Streams
+
+Spinners print to `stdout` by default. Use [`Spinner::new_with_stream`] to print to `stderr`,
+or to any other writer through [`Output::Writer`]:
+
+```
+# use spinoff::*;
+# use std::sync::{Arc, Mutex};
+# use std::thread::sleep;
+# use std::time::Duration;
+#
+let buffer = Arc::new(Mutex::new(Vec::new()));
+let mut sp = Spinner::new_with_stream(spinners::Dots, "Capturing...", None, Output::Writer(buffer.clone()));
+sleep(Duration::from_millis(800));
+sp.success("Captured!");
+let output = String::from_utf8(buffer.lock().unwrap().clone()).unwrap();
+assert!(output.ends_with("Captured!\n"));
+```
+
+When the stream is not a terminal, e.g. because the output is piped into a file or a CI log,
+no frames are drawn. The message and every change to it are printed on their own lines instead,
+followed by the final line.
+
+### Cursor
+
+While a spinner is animating, the cursor is hidden. It is shown again when the spinner stops,
+is dropped, or unwinds from a panic. The default `signals` feature also restores it when the process
+is interrupted with Ctrl-C or terminated. Applications with their own signal handlers can disable it
+and call [`restore_terminal`] from their handler instead.
+
+### Async
+
+With the `tokio` feature enabled, `AsyncSpinner` offers the same API as [`Spinner`], but is
+driven by a tokio task instead of an OS thread, and its `stop` type methods are `async`.
+
+### Multiple spinners
+
+A single [`Spinner`] owns the current terminal line, so two of them will overwrite each other.
+To show several spinners at once, use a [`MultiSpinner`], which renders each of its lines
+from one thread and lets every line be finished on its own.
+
+```
+# use spinoff::*;
+# use std::thread::sleep;
+# use std::time::Duration;
+#
+let mut multi = MultiSpinner::new();
+let build = multi.add(spinners::Dots, "Building...", Color::Blue);
+let test = multi.add(spinners::Dots, "Testing...", Color::Yellow);
+sleep(Duration::from_millis(800));
+build.success("Built!");
+sleep(Duration::from_millis(800));
+test.success("Tested!");
+multi.stop();
+```
 
 */
 #![allow(clippy::nursery)]
 #![warn(clippy::pedantic)]
-use colored::Colorize;
 use std::borrow::Cow;
-use std::io::Write;
-use std::sync::{atomic::AtomicBool, Arc};
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
 use std::thread::sleep;
 use std::thread::{self, JoinHandle};
-use std::time::Duration;
-
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "tokio")]
+mod async_spinner;
+mod cursor;
+mod future;
+mod handle;
+mod iter;
+mod multi;
 pub mod spinners;
+mod state;
 mod streams;
+mod style;
+mod symbols;
+mod template;
 mod utils;
 
+#[cfg(feature = "tokio")]
+pub use async_spinner::AsyncSpinner;
+pub use cursor::restore_terminal;
+pub use future::{SpinFuture, WithSpinner};
+pub use iter::{Spin, SpinIterator};
+pub use multi::{MultiSpinner, SpinnerLine};
 use spinners::SpinnerFrames;
-pub use streams::Streams;
+pub use streams::{Output, Routing, Streams};
+pub use style::{set_default_color_policy, ColorPolicy, Style};
+pub use symbols::{set_default_symbols, Symbols};
+pub use template::Template;
 pub use utils::Color;
-use utils::{colorize, delete_last_line};
+use handle::Handle;
+use state::{Finish, Status};
+use utils::lock;
 
 /// Terminal spinner.
 pub struct Spinner {
     thread_handle: Option<JoinHandle<()>>,
-    /// This struct has an `Arc<AtomicBool>` field, which is later used in the `stop` type methods to stop the thread printing the spinner.
-    still_spinning: Arc<AtomicBool>,
-    spinner_frames: SpinnerFrames,
-    msg: Cow<'static, str>,
-    stream: Streams,
-    color: Option<Color>,
+    /// The state shared with the spinner thread, and the `stop` type methods.
+    handle: Handle,
+}
+
+/// What a spinner does when it is dropped while still spinning,
+/// e.g. because `?` returned early or the thread is panicking.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+#[non_exhaustive]
+pub enum DropBehavior {
+    /// Clear the spinner, like [`Spinner::clear`].
+    #[default]
+    Clear,
+    /// Keep the current message, like [`Spinner::stop`].
+    Persist,
+    /// Mark the spinner as failed with its current message, like [`Spinner::fail`], if the thread is panicking.
+    /// Clear it otherwise.
+    FailOnPanic,
 }
 
 /**
@@ -83,9 +186,12 @@ Create a new `SpinnerFrames` struct
 
 # Arguments
 
-* `frames` - An array of frames you want to use
+* `frames` - An array of frames you want to use, either `&'static str` or `String`
 * `interval` - The time (in milliseconds) that will pass between frames
 
+Instead of a shared interval, every frame can be given its own duration (in milliseconds)
+by passing an array of `(frame, duration)` pairs.
+
 # Example
 
 ```
@@ -97,14 +203,49 @@ let frames = spinner!([">", ">>", ">>>"], 100);
 let mut sp = Spinner::new(frames, "Hello World!", None);
 sleep(Duration::from_millis(800));
 sp.stop();
+```
+
+```
+# use spinoff::*;
+# use std::thread::sleep;
+# use std::time::Duration;
+#
+let frames = spinner!([("💣", 400), ("💥", 100)]);
+let mut sp = Spinner::new(frames, "Tick, tick...", None);
+sleep(Duration::from_millis(800));
+sp.stop();
+```
+
+At least one pair is needed:
+
+```compile_fail
+# use spinoff::*;
+let frames = spinner!([]);
 ```
  */
 #[macro_export]
 macro_rules! spinner {
+    // The first pair is matched on its own, so that there is always a first duration to use as the interval.
+    ( [ ( $frame:expr, $duration:expr ) $(, ( $frames:expr, $durations:expr ) )* $(,)? ] ) => {
+        {
+            let interval: u16 = $duration;
+            spinners::SpinnerFrames {
+                interval,
+                frames: vec![
+                    ::std::borrow::Cow::from($frame),
+                    $(::std::borrow::Cow::from($frames)),*
+                ],
+                durations: vec![interval, $($durations),*],
+                playback: spinners::Playback::Loop,
+            }
+        }
+    };
     ( [ $( $frame:expr ),* ], $interval:expr ) => {
         spinners::SpinnerFrames {
             interval: $interval,
-            frames: vec![$($frame),*]
+            frames: vec![$(::std::borrow::Cow::from($frame)),*],
+            durations: ::std::vec::Vec::new(),
+            playback: spinners::Playback::Loop,
         }
     };
 }
@@ -142,7 +283,7 @@ impl Spinner {
         T: Into<Cow<'static, str>>,
         U: Into<Option<Color>>,
     {
-        Self::new_with_stream(spinner_type, msg, color, Streams::default())
+        Self::new_with_stream(spinner_type, msg, color, Output::default())
     }
     /**
     Create a new spinner outputting to a specific stream.
@@ -152,7 +293,7 @@ impl Spinner {
     * `spinner_type` - The spinner to use.
     * `msg` - The message to display.
     * `color` - The color of the spinner.
-    * `stream` - The stream to output to.
+    * `stream` - The stream to output to, one of the [`Streams`] or any [`Output`].
 
     # Example
 
@@ -169,63 +310,100 @@ impl Spinner {
     # Notes
 
     * The spinner immediately starts spinning upon creation.
+    * If the stream is not a terminal (see [`Output::is_terminal`]), no frames are drawn.
+      Instead, the message and every change to it are printed on their own lines.
+
+    # Panics
+
+    * The spinner thread panics if the stream cannot be flushed.
 
     */
-    pub fn new_with_stream<S, T, U>(spinner_type: S, msg: T, color: U, stream: Streams) -> Self
+    pub fn new_with_stream<S, T, U, O>(spinner_type: S, msg: T, color: U, stream: O) -> Self
     where
         S: Into<SpinnerFrames>,
         T: Into<Cow<'static, str>>,
         U: Into<Option<Color>>,
+        O: Into<Output>,
     {
-        let still_spinning = Arc::new(AtomicBool::new(true));
-        // Gain ownership of the message and color for the thread to use
-        let spinner_frames = spinner_type.into();
-        let msg = msg.into();
-        let color = color.into();
-        // We use atomic bools to make the thread stop itself when the `spinner.stop()` method is called.
-        let handle = thread::spawn({
-            // Clone the atomic bool so that we can use it in the thread and return the original one later.
-            let still_spinning = Arc::clone(&still_spinning);
-            let spinner_frames = spinner_frames.clone();
-            let msg = msg.clone();
-            move || {
-                // Iterate over all the frames of the spinner while the atomic bool is true.
-                let frames = spinner_frames
-                    .frames
-                    .iter()
-                    .cycle()
-                    .take_while(|_| still_spinning.load(std::sync::atomic::Ordering::Relaxed));
-                // Dynamically delete the last line of the terminal depending on the length of the message + spinner.
-                let mut last_length = 0;
-                for frame in frames {
-                    let frame_str = format!("{} {}", colorize(color, frame), msg);
-                    // Get us back to the start of the line.
-                    delete_last_line(last_length, stream);
-                    last_length = frame_str.bytes().len();
-                    write!(stream, "{frame_str}");
-                    stream
-                        .get_stream()
-                        .flush()
-                        .expect("error: failed to flush stream");
-
-                    thread::sleep(std::time::Duration::from_millis(
-                        u64::from(spinner_frames.interval)
-                    ));
+        let handle = Handle::new(spinner_type.into(), msg.into(), color.into(), stream.into());
+        let thread_handle = handle.is_animated().then(|| {
+            thread::spawn({
+                // Clone the atomic bool, the state and the stream so that we can use them in the thread.
+                let still_spinning = Arc::clone(&handle.still_spinning);
+                let state = Arc::clone(&handle.state);
+                let stream = handle.stream.clone();
+                move || loop {
+                    let interval = {
+                        let mut state = lock(&state);
+                        // Checked while holding the lock, so nothing is drawn once the spinner is stopped.
+                        if !still_spinning.load(Ordering::Relaxed) {
+                            break;
+                        }
+                        state.draw(&stream);
+                        // Show the drawn frame for as long as it asks for.
+                        let interval = state.interval();
+                        state.advance();
+                        interval
+                    };
+                    // Parked rather than asleep, so that stopping the spinner wakes the thread right away.
+                    let deadline = Instant::now() + interval;
+                    while still_spinning.load(Ordering::Relaxed) {
+                        let now = Instant::now();
+                        if now >= deadline {
+                            break;
+                        }
+                        thread::park_timeout(deadline - now);
+                    }
                 }
-                delete_last_line(last_length, stream);
-            }
+            })
         });
-
-        // Return a Spinner struct
         Self {
-            thread_handle: Some(handle),
-            still_spinning,
-            spinner_frames,
-            msg,
-            stream,
-            color,
+            thread_handle,
+            handle,
         }
     }
+
+    /**
+    Creates a spinner, runs `f` while it spins, then prints a success line with the message if `f` returns `Ok`,
+    or a failure line with the error if it returns `Err`. The result of `f` is returned.
+
+    # Arguments
+
+    * `spinner_type` - The spinner to use.
+    * `msg` - The message to display.
+    * `f` - The work to do.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let manifest = Spinner::run(spinners::Dots, "Reading manifest...", || {
+        sleep(Duration::from_millis(800));
+        std::fs::read_to_string("Cargo.toml")
+    });
+    assert!(manifest.is_ok());
+    ```
+
+    # Errors
+
+    * The error returned by `f`, after the failure line is printed.
+
+    # Notes
+
+    * To pick a color, stream or other settings, create the spinner yourself and use [`Spinner::stop_after`].
+    */
+    pub fn run<S, T, F, R, E>(spinner_type: S, msg: T, f: F) -> Result<R, E>
+    where
+        S: Into<SpinnerFrames>,
+        T: Into<Cow<'static, str>>,
+        F: FnOnce() -> Result<R, E>,
+        E: std::fmt::Display,
+    {
+        Self::new(spinner_type, msg, None).stop_after(f)
+    }
     /**
     Stop the spinner.
 
@@ -248,9 +426,7 @@ impl Spinner {
 
     */
     pub fn stop(&mut self) {
-        self.stop_spinner_thread();
-        // print message
-        writeln!(self.stream, "{}", self.msg);
+        self.finish(Finish::Stop);
     }
 
     /**
@@ -271,9 +447,7 @@ impl Spinner {
 
     */
     pub fn stop_with_message(&mut self, msg: &str) {
-        self.stop_spinner_thread();
-        // put the message over the spinner
-        writeln!(self.stream, "{msg}");
+        self.finish(Finish::Message(msg));
     }
 
     /**
@@ -294,8 +468,7 @@ impl Spinner {
 
     */
     pub fn stop_and_persist(&mut self, symbol: &str, msg: &str) {
-        self.stop_spinner_thread();
-        writeln!(self.stream, "{symbol} {msg}");
+        self.finish(Finish::Persist(symbol, msg));
     }
 
     /**
@@ -316,12 +489,11 @@ impl Spinner {
 
     */
     pub fn success(&mut self, msg: &str) {
-        self.stop_spinner_thread();
-        writeln!(self.stream, "{} {}", colorize(Some(Color::Green), "✓").bold(), msg);
+        self.finish(Finish::Outcome(Status::Success, msg));
     }
 
     /**
-    Deletes the last line of the terminal and prints a failure symbol with a message to stderr.
+    Deletes the last line of the terminal and prints a failure symbol with a message.
 
     # Example
 
@@ -336,10 +508,12 @@ impl Spinner {
     #
     ```
 
+    # Notes
+
+    * The message goes to the spinner's stream, like the other lines. Use [`Spinner::set_routing`] to send it to stderr instead.
     */
     pub fn fail(&mut self, msg: &str) {
-        self.stop_spinner_thread();
-        writeln!(self.stream, "{} {}", colorize(Some(Color::Red), "✗").bold(), msg);
+        self.finish(Finish::Outcome(Status::Fail, msg));
     }
 
     /**
@@ -360,8 +534,7 @@ impl Spinner {
 
     */
     pub fn warn(&mut self, msg: &str) {
-        self.stop_spinner_thread();
-        writeln!(self.stream, "{} {}", colorize(Some(Color::Yellow), "⚠").bold(), msg);
+        self.finish(Finish::Outcome(Status::Warn, msg));
     }
     /**
     Deletes the last line of the terminal and prints an info symbol with a message.
@@ -381,8 +554,83 @@ impl Spinner {
 
     */
     pub fn info(&mut self, msg: &str) {
-        self.stop_spinner_thread();
-        writeln!(self.stream, "{} {}", colorize(Some(Color::Blue), "ℹ").bold(), msg);
+        self.finish(Finish::Outcome(Status::Info, msg));
+    }
+
+    /**
+    Runs `f` while the spinner spins, then prints a success line with the current message if it returns `Ok`,
+    or a failure line with the error if it returns `Err`. The result of `f` is returned.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut sp = Spinner::new(spinners::Dots, "Reading config...", None);
+    let config = sp.stop_after(|| {
+        sleep(Duration::from_millis(800));
+        std::fs::read_to_string("Cargo.toml")
+    });
+    assert!(config.is_ok());
+    ```
+
+    # Errors
+
+    * The error returned by `f`, after the failure line is printed.
+
+    # Notes
+
+    * Use [`Spinner::stop_after_with`] to write the failure line yourself.
+    */
+    pub fn stop_after<F, R, E>(&mut self, f: F) -> Result<R, E>
+    where
+        F: FnOnce() -> Result<R, E>,
+        E: std::fmt::Display,
+    {
+        self.stop_after_with(f, ToString::to_string)
+    }
+
+    /**
+    Like [`Spinner::stop_after`], but the failure line is the message returned by `fail_msg` for the error.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut sp = Spinner::new(spinners::Dots, "Connecting...", None);
+    let result: Result<(), u16> = sp.stop_after_with(
+        || {
+            sleep(Duration::from_millis(800));
+            Err(503)
+        },
+        |status| format!("Server returned {status}"),
+    );
+    assert_eq!(result, Err(503));
+    ```
+
+    # Errors
+
+    * The error returned by `f`, after the failure line is printed.
+    */
+    pub fn stop_after_with<F, R, E, M>(&mut self, f: F, fail_msg: M) -> Result<R, E>
+    where
+        F: FnOnce() -> Result<R, E>,
+        M: FnOnce(&E) -> String,
+    {
+        let result = f();
+        match &result {
+            Ok(_) => {
+                let msg = self.handle.state().msg.clone();
+                self.success(&msg);
+            }
+            Err(err) => self.fail(&fail_msg(err)),
+        }
+        result
     }
 
     /**
@@ -405,6 +653,10 @@ impl Spinner {
     #
     ```
 
+    # Notes
+
+    * The spinner thread keeps running, so the animation continues from the frame it was on.
+
     */
     pub fn update<S, T, U>(&mut self, spinner: S, msg: T, color: U)
     where
@@ -412,11 +664,8 @@ impl Spinner {
         T: Into<Cow<'static, str>>,
         U: Into<Option<Color>>,
     {
-        self.stop_spinner_thread();
-        let _replaced = std::mem::replace(
-            self,
-            Self::new_with_stream(spinner, msg, color, self.stream),
-        );
+        self.handle
+            .change(|state, _| state.update(spinner.into(), msg.into(), color.into()));
     }
 
     /**
@@ -444,11 +693,7 @@ impl Spinner {
     where
         T: Into<Cow<'static, str>>,
     {
-        self.stop_spinner_thread();
-        let _replaced = std::mem::replace(
-            self,
-            Self::new_with_stream(self.spinner_frames.clone(), msg, self.color, self.stream),
-        );
+        self.handle.change(|state, _| state.msg = msg.into());
     }
     /**
     Updates the spinner text after a certain amount of time has passed since the initial `::new` call.
@@ -477,12 +722,354 @@ impl Spinner {
         T: Into<Cow<'static, str>>
     {
         sleep(duration);
-        self.stop_spinner_thread();
-        let _replaced = std::mem::replace(
-            self,
-            Self::new_with_stream(self.spinner_frames.clone(), updated_msg, self.color, self.stream),
-        );
+        self.update_text(updated_msg);
+    }
+    /**
+    Prints a line above the spinner.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut sp = Spinner::new(spinners::Dots, "Compiling...", None);
+    sleep(Duration::from_millis(800));
+    sp.println("Compiling spinoff v0.8.0");
+    sleep(Duration::from_millis(800));
+    sp.success("Compiled!");
+    ```
+
+    */
+    pub fn println(&self, msg: &str) {
+        self.handle.println(msg);
+    }
+
+    /**
+    Removes the spinner from the screen while `f` runs, then draws it again below its output.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut sp = Spinner::new(spinners::Dots, "Compiling...", None);
+    sleep(Duration::from_millis(800));
+    sp.suspend(|| {
+        println!("warning: unused variable");
+        println!("warning: unused import");
+    });
+    sleep(Duration::from_millis(800));
+    sp.success("Compiled!");
+    ```
+
+    # Notes
+
+    * The spinner thread waits until `f` returns, so `f` must not use the spinner itself.
+    */
+    pub fn suspend<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        self.handle.suspend(f)
+    }
+
+    /**
+    Sets the total amount of work, e.g. the number of files to upload.
+
+    While a total is set, a progress bar and percentage are shown next to the message,
+    e.g. `⠋ Uploading... [#####-----] 52%`. Pass `None` to go back to just the spinner.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut sp = Spinner::new(spinners::Dots, "Uploading...", None);
+    sp.set_total(340);
+    for _ in 0..340 {
+        sleep(Duration::from_millis(2));
+        sp.inc(1);
+    }
+    sp.success("Uploaded!");
+    ```
+
+    */
+    pub fn set_total<T>(&mut self, total: T)
+    where
+        T: Into<Option<u64>>,
+    {
+        self.handle.change(|state, _| state.total = total.into());
+    }
+
+    /**
+    Sets the amount of work done so far.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut sp = Spinner::new(spinners::Dots, "Uploading...", None);
+    sp.set_total(100);
+    sleep(Duration::from_millis(400));
+    sp.set_position(52);
+    sleep(Duration::from_millis(400));
+    sp.success(&format!("Uploaded {} files!", sp.position()));
+    ```
+
+    # Notes
+
+    * The progress bar is updated on the next frame, so this is cheap to call often.
+    */
+    pub fn set_position(&mut self, position: u64) {
+        self.handle.state().position = position;
+    }
+
+    /// Adds `delta` to the amount of work done so far. See [`Spinner::set_total`].
+    pub fn inc(&mut self, delta: u64) {
+        let mut state = self.handle.state();
+        state.position = state.position.saturating_add(delta);
+    }
+
+    /// Returns the amount of work done so far.
+    #[must_use]
+    pub fn position(&self) -> u64 {
+        self.handle.state().position
+    }
+
+    /**
+    Shows how long the spinner has been running next to its message, e.g. `⠋ Compiling (12.4s)`.
+
+    The final duration is also added to the line printed by the `stop` type methods.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut sp = Spinner::new(spinners::Dots, "Compiling...", None);
+    sp.show_elapsed(true);
+    sleep(Duration::from_millis(800));
+    sp.success("Compiled!");
+    ```
+
+    */
+    pub fn show_elapsed(&mut self, show: bool) {
+        self.handle.change(|state, _| state.show_elapsed = show);
     }
+
+    /**
+    Lays out the spinner line with a [`Template`], e.g. `"{spinner} {msg:.40} [{elapsed}]"`.
+
+    The template is also used for the lines printed by [`Spinner::stop_and_persist`] and the `success` type methods,
+    with `{spinner}` replaced by the symbol.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut sp = Spinner::new(spinners::Dots, "Compiling...", None);
+    sp.set_template("[{elapsed:>6}] {spinner} {msg}");
+    sleep(Duration::from_millis(800));
+    sp.success("Compiled!");
+    ```
+
+    */
+    pub fn set_template<T: Into<Template>>(&mut self, template: T) {
+        self.handle
+            .change(|state, _| state.template = Some(template.into()));
+    }
+
+    /**
+    Sets the [`Style`] of the frame. Its color takes precedence over the color of the spinner.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut sp = Spinner::new(spinners::Dots, "Loading...", Color::BrightCyan);
+    sp.set_frame_style(Style::new().bold().on(Color::Ansi256(236)));
+    sleep(Duration::from_millis(800));
+    sp.stop();
+    ```
+
+    */
+    pub fn set_frame_style(&mut self, style: Style) {
+        self.handle.change(|state, _| state.frame_style = style);
+    }
+
+    /**
+    Sets the [`Style`] of the message, including the messages printed by the `stop` type methods.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut sp = Spinner::new(spinners::Dots, "Loading...", None);
+    sp.set_message_style(Style::new().italic().color(Color::BrightBlack));
+    sleep(Duration::from_millis(800));
+    sp.stop();
+    ```
+
+    */
+    pub fn set_message_style(&mut self, style: Style) {
+        self.handle.change(|state, _| state.msg_style = style);
+    }
+
+    /**
+    Sets the [`Style`] of the symbols printed by the `success` type methods. By default they are bold.
+    Its color takes precedence over the color of the outcome, e.g. green for [`Spinner::success`].
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut sp = Spinner::new(spinners::Dots, "Loading...", None);
+    sp.set_symbol_style(Style::new().bold().underline());
+    sleep(Duration::from_millis(800));
+    sp.success("Loaded!");
+    ```
+
+    */
+    pub fn set_symbol_style(&mut self, style: Style) {
+        self.handle.state().symbol_style = style;
+    }
+
+    /**
+    Sets the symbols printed by the `success` type methods, overriding the default set with [`set_default_symbols`].
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut sp = Spinner::new(spinners::Line, "Loading...", None);
+    sp.set_symbols(Symbols::ASCII);
+    sleep(Duration::from_millis(800));
+    sp.success("Loaded!");
+    ```
+
+    */
+    pub fn set_symbols(&mut self, symbols: Symbols) {
+        self.handle.state().symbols = symbols;
+    }
+
+    /**
+    Sets the streams the lines printed by the `success` type methods go to, instead of the spinner's own stream.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut sp = Spinner::new(spinners::Dots, "Checking...", None);
+    sp.set_routing(Routing::split().warnings_to_stderr());
+    sleep(Duration::from_millis(800));
+    sp.warn("Printed to stderr!");
+    ```
+
+    */
+    pub fn set_routing(&mut self, routing: Routing) {
+        self.handle.state().routing = routing;
+    }
+
+    /**
+    Sets whether the spinner prints colors and styles, overriding the default set with [`set_default_color_policy`].
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut sp = Spinner::new(spinners::Dots, "Loading...", Color::Blue);
+    sp.set_color_policy(ColorPolicy::Always);
+    sleep(Duration::from_millis(800));
+    sp.success("Colored, even when piped!");
+    ```
+
+    */
+    pub fn set_color_policy(&mut self, policy: ColorPolicy) {
+        self.handle
+            .change(|state, stream| state.set_color_policy(policy, stream));
+    }
+
+    /**
+    Sets what the spinner does when it is dropped while still spinning. By default, it is cleared.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    fn compile() -> Result<(), String> {
+        let mut sp = Spinner::new(spinners::Dots, "Compiling...", None);
+        sp.set_drop_behavior(DropBehavior::Persist);
+        sleep(Duration::from_millis(800));
+        // The spinner is stopped here, and "Compiling..." stays on the screen.
+        Err("Compilation failed!".to_string())?;
+        sp.success("Compiled!");
+        Ok(())
+    }
+    # let _ = compile();
+    ```
+
+    */
+    pub fn set_drop_behavior(&mut self, behavior: DropBehavior) {
+        self.handle.drop_behavior = behavior;
+    }
+
+    /**
+    Returns the time since the spinner was created.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut sp = Spinner::new(spinners::Dots, "Compiling...", None);
+    sleep(Duration::from_millis(800));
+    let elapsed = sp.elapsed();
+    sp.success(&format!("Compiled in {}ms!", elapsed.as_millis()));
+    ```
+
+    */
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.handle.state().started.elapsed()
+    }
+
     /**
     Deletes the last line of the terminal.
 
@@ -501,21 +1088,174 @@ impl Spinner {
 
     */
     pub fn clear(&mut self) {
-        self.stop_spinner_thread();
-    }
-
-    /// Stop the spinner thread and wait for it.
-    fn stop_spinner_thread(&mut self) {
-        // Set flag to signal thread to stop
-        self.still_spinning
-            .store(false, std::sync::atomic::Ordering::Relaxed);
-
-        // Wait for the thread to actually stop
-        // Also deletes the last line of the terminal after stopped
-        self.thread_handle
-            .take()
-            .expect("Stopping the spinner thread should only happen once.")
-            .join()
-            .expect("Thread to join.");
+        self.finish(Finish::Clear);
+    }
+
+    /// Stop the spinner and print what is left in its place.
+    fn finish(&mut self, finish: Finish<'_>) {
+        self.handle.finish(finish);
+        self.join_spinner_thread();
+    }
+
+    /// Wait for the spinner thread, once the spinner is stopped.
+    fn join_spinner_thread(&mut self) {
+        if let Some(handle) = self.thread_handle.take() {
+            // Wake the thread up, so it sees that it was stopped without finishing its current frame.
+            handle.thread().unpark();
+            handle.join().expect("Thread to join.");
+        }
+    }
+}
+
+impl Drop for Spinner {
+    /// Stops the spinner according to its [`DropBehavior`] if none of the `stop` type methods were called.
+    fn drop(&mut self) {
+        self.handle.finish_dropped();
+        self.join_spinner_thread();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{self, AssertUnwindSafe};
+    use std::sync::Mutex;
+
+    use super::*;
+
+    /// A spinner animated on a captured terminal, without colors so the output is plain text.
+    fn on_terminal(behavior: DropBehavior) -> (Arc<Mutex<Vec<u8>>>, Spinner) {
+        let buffer = buffer();
+        let mut spinner = Spinner::new_with_stream(
+            SpinnerFrames::new(["-"], 100),
+            "Working",
+            None,
+            Output::Terminal(buffer.clone()),
+        );
+        spinner.set_color_policy(ColorPolicy::Never);
+        spinner.set_symbols(Symbols::ASCII);
+        spinner.set_drop_behavior(behavior);
+        (buffer, spinner)
+    }
+
+    /// What was printed after the spinner was removed and the cursor shown again.
+    fn left_behind(buffer: &Mutex<Vec<u8>>) -> String {
+        let output = output(buffer);
+        let (_, rest) = output
+            .rsplit_once("\x1b[?25h")
+            .expect("the cursor to be shown again");
+        rest.to_string()
+    }
+
+    #[test]
+    fn dropping_with_clear_leaves_nothing() {
+        let (buffer, spinner) = on_terminal(DropBehavior::Clear);
+        drop(spinner);
+        assert_eq!(left_behind(&buffer), "");
+    }
+
+    #[test]
+    fn dropping_with_persist_leaves_the_message() {
+        let (buffer, spinner) = on_terminal(DropBehavior::Persist);
+        drop(spinner);
+        assert_eq!(left_behind(&buffer), "Working\n");
+    }
+
+    #[test]
+    fn dropping_with_fail_on_panic_fails_only_while_panicking() {
+        let (buffer, spinner) = on_terminal(DropBehavior::FailOnPanic);
+        drop(spinner);
+        assert_eq!(left_behind(&buffer), "");
+
+        let (buffer, spinner) = on_terminal(DropBehavior::FailOnPanic);
+        let result = panic::catch_unwind(AssertUnwindSafe(move || {
+            let _spinner = spinner;
+            panic!("failed while spinning");
+        }));
+        assert!(result.is_err());
+        assert_eq!(left_behind(&buffer), "[x] Working\n");
+    }
+
+    #[test]
+    fn dropping_after_stop_does_nothing() {
+        let (buffer, mut spinner) = on_terminal(DropBehavior::Persist);
+        spinner.clear();
+        drop(spinner);
+        assert_eq!(left_behind(&buffer), "");
+    }
+
+    fn buffer() -> Arc<Mutex<Vec<u8>>> {
+        Arc::new(Mutex::new(Vec::new()))
+    }
+
+    fn output(buffer: &Mutex<Vec<u8>>) -> String {
+        String::from_utf8(lock(buffer).clone()).unwrap()
+    }
+
+    /// A spinner printing plain lines to `stream`, with the outcome lines routed by `routing`.
+    fn captured(stream: &Arc<Mutex<Vec<u8>>>, routing: Routing) -> Spinner {
+        let mut spinner = Spinner::new_with_stream(
+            SpinnerFrames::new(["-"], 100),
+            "Working",
+            None,
+            Output::Writer(stream.clone()),
+        );
+        spinner.set_color_policy(ColorPolicy::Never);
+        spinner.set_symbols(Symbols::ASCII);
+        spinner.set_routing(routing);
+        spinner
+    }
+
+    /// Like [`Routing::split`], with buffers in place of stdout and stderr.
+    fn split(out: &Arc<Mutex<Vec<u8>>>, err: &Arc<Mutex<Vec<u8>>>) -> Routing {
+        Routing {
+            success: Some(Output::Writer(out.clone())),
+            fail: Some(Output::Writer(err.clone())),
+            warn: Some(Output::Writer(out.clone())),
+            info: Some(Output::Writer(out.clone())),
+        }
+    }
+
+    #[test]
+    fn split_routing_sends_failures_to_the_fail_stream() {
+        let (stream, out, err) = (buffer(), buffer(), buffer());
+        captured(&stream, split(&out, &err)).fail("Failed");
+        assert_eq!(output(&stream), "Working\n");
+        assert_eq!(output(&out), "");
+        assert_eq!(output(&err), "[x] Failed\n");
+    }
+
+    #[test]
+    fn split_routing_sends_the_other_outcomes_to_the_other_stream() {
+        let (stream, out, err) = (buffer(), buffer(), buffer());
+        captured(&stream, split(&out, &err)).success("Done");
+        captured(&stream, split(&out, &err)).warn("Careful");
+        captured(&stream, split(&out, &err)).info("Note");
+        assert_eq!(output(&stream), "Working\n".repeat(3));
+        assert_eq!(output(&out), "[ok] Done\n[!] Careful\n[i] Note\n");
+        assert_eq!(output(&err), "");
+    }
+
+    #[test]
+    fn unrouted_outcomes_go_to_the_spinner_stream() {
+        let (stream, err) = (buffer(), buffer());
+        let routing = Routing {
+            fail: Some(Output::Writer(err.clone())),
+            ..Routing::default()
+        };
+        captured(&stream, routing).success("Done");
+        assert_eq!(output(&stream), "Working\n[ok] Done\n");
+        assert_eq!(output(&err), "");
+    }
+
+    #[test]
+    fn split_routing_uses_stderr_for_failures_only() {
+        let stream = |routing: &Routing, status| format!("{:?}", routing.get(status));
+        let routing = Routing::split();
+        assert_eq!(stream(&routing, Status::Fail), "Some(Stream(Stderr))");
+        for status in [Status::Success, Status::Warn, Status::Info] {
+            assert_eq!(stream(&routing, status), "Some(Stream(Stdout))");
+        }
+        let routing = routing.warnings_to_stderr();
+        assert_eq!(stream(&routing, Status::Warn), "Some(Stream(Stderr))");
     }
 }
@@ -0,0 +1,1110 @@
+use std::borrow::Cow;
+use std::fmt::Write as _;
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crate::cursor::HiddenCursor;
+use crate::spinners::SpinnerFrames;
+use crate::state::{Finish, SpinnerState, Status};
+use crate::utils::{display_width, lock, wrapped_rows};
+use crate::{Color, ColorPolicy, DropBehavior, Output, Routing, Style, Symbols, Template};
+
+/// The longest time the render thread waits before checking for new lines or a stop request.
+const MAX_TICK: Duration = Duration::from_millis(100);
+
+/// Several spinners rendered on separate lines by a single thread.
+///
+/// Lines are added with [`MultiSpinner::add`], which returns a [`SpinnerLine`] handle.
+/// Each line animates with its own frames and interval and can be finished on its own,
+/// while the remaining lines keep spinning. Finished lines are printed once above the lines
+/// that are still spinning, so only those are redrawn.
+pub struct MultiSpinner {
+    thread_handle: Option<JoinHandle<()>>,
+    shared: Arc<Shared>,
+}
+
+/// Handle to a single line of a [`MultiSpinner`].
+///
+/// Handles can be moved to other threads, so every job can finish its own line.
+/// Dropping a handle while its line is still spinning stops the line according to its [`DropBehavior`].
+pub struct SpinnerLine {
+    shared: Arc<Shared>,
+    index: usize,
+}
+
+/// State shared between the multi spinner, its lines and the render thread.
+struct Shared {
+    lines: Mutex<Vec<Line>>,
+    /// Cleared by [`MultiSpinner::stop`] to make the render thread stop itself.
+    still_spinning: AtomicBool,
+    /// Number of terminal rows written by the previous draw, including wrapped ones.
+    /// Only changed while `lines` is locked.
+    drawn: AtomicUsize,
+    stream: Output,
+    /// Whether the lines are drawn on a terminal. Otherwise every change is printed on a new line.
+    animated: bool,
+}
+
+/// State of one line, shared between the handles and the render thread.
+struct Line {
+    state: SpinnerState,
+    next_frame: Instant,
+    status: LineStatus,
+    drop_behavior: DropBehavior,
+}
+
+enum LineStatus {
+    Spinning,
+    /// Finished or cleared, and no longer drawn.
+    Done,
+}
+
+impl Shared {
+    /// Draw every line over the previous draw, moving the lines that are due on to their next frame.
+    /// Returns when the next frame is due, if any line is still spinning.
+    fn draw(&self, lines: &mut [Line]) -> Option<Instant> {
+        let now = Instant::now();
+        let columns = self.stream.columns();
+        let mut output = String::new();
+        // Go back to the first line of the previous draw.
+        let drawn = self.drawn.load(Ordering::Relaxed);
+        if drawn > 0 {
+            write!(output, "\x1b[{drawn}A").unwrap();
+        }
+        let mut drawn = 0;
+        for line in lines.iter_mut() {
+            if matches!(line.status, LineStatus::Spinning) && now >= line.next_frame {
+                line.state.advance();
+                line.next_frame = now + line.state.interval();
+            }
+            if let Some(text) = line.render() {
+                drawn += wrapped_rows(display_width(&text), columns);
+                // Clear the rest of the row, wrapped rows before it are written over completely.
+                write!(output, "\r{text}\x1b[K\n").unwrap();
+            }
+        }
+        self.drawn.store(drawn, Ordering::Relaxed);
+        // Remove whatever is left below the lines, e.g. after a line was finished.
+        output.push_str("\x1b[J");
+        write!(self.stream, "{output}");
+        self.stream
+            .get_stream()
+            .flush()
+            .expect("error: failed to flush stream");
+        lines
+            .iter()
+            .filter(|line| matches!(line.status, LineStatus::Spinning))
+            .map(|line| line.next_frame)
+            .min()
+    }
+
+    /// Remove everything written by the previous draw, leaving the cursor where the first line was.
+    fn clear(&self) {
+        let drawn = self.drawn.swap(0, Ordering::Relaxed);
+        if drawn > 0 {
+            write!(self.stream, "\x1b[{drawn}A");
+        }
+        write!(self.stream, "\r\x1b[J");
+        let _ = self.stream.get_stream().flush();
+    }
+
+    /// Run `f` with the lines removed from the screen, then draw them again below whatever `f` printed.
+    fn suspend<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&mut [Line]) -> R,
+    {
+        // Holding the lock keeps the render thread from drawing in between.
+        let mut lines = lock(&self.lines);
+        let drawing = self.animated && self.still_spinning.load(Ordering::Relaxed);
+        if drawing {
+            self.clear();
+        }
+        let result = f(&mut lines);
+        if drawing {
+            self.draw(&mut lines);
+        }
+        result
+    }
+
+    fn println(&self, msg: &str) {
+        self.suspend(|_| writeln!(self.stream, "{msg}"));
+    }
+
+    /// Print a changed message right away when there is no render thread to pick it up.
+    fn print_change(&self, state: &mut SpinnerState) {
+        if !self.animated {
+            state.draw(&self.stream);
+        }
+    }
+}
+
+impl Line {
+    fn render(&self) -> Option<String> {
+        match self.status {
+            LineStatus::Spinning => Some(self.state.render()),
+            LineStatus::Done => None,
+        }
+    }
+}
+
+impl MultiSpinner {
+    /**
+    Create a new, empty multi spinner.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut multi = MultiSpinner::new();
+    let first = multi.add(spinners::Dots, "Compiling...", Color::Blue);
+    let second = multi.add(spinners::Line, "Testing...", None);
+    sleep(Duration::from_millis(800));
+    first.success("Compiled!");
+    sleep(Duration::from_millis(800));
+    second.fail("Tests failed!");
+    multi.stop();
+    ```
+
+    # Notes
+
+    * The render thread starts immediately, lines start spinning as soon as they are added.
+    * This function outputs to the `stdout` stream. If you want to use a different stream, use the [`MultiSpinner::new_with_stream`] function.
+    */
+    #[must_use]
+    pub fn new() -> Self {
+        Self::new_with_stream(Output::default())
+    }
+
+    /**
+    Create a new, empty multi spinner outputting to a specific stream.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut multi = MultiSpinner::new_with_stream(Streams::Stderr);
+    let line = multi.add(spinners::Dots, "I'm outputting to stderr!", Color::Yellow);
+    sleep(Duration::from_millis(800));
+    line.clear();
+    multi.stop();
+    ```
+
+    # Notes
+
+    * If the stream is not a terminal (see [`Output::is_terminal`]), no render thread is started.
+      Instead, the messages and final lines are printed one after another as they happen.
+
+    # Panics
+
+    * The render thread panics if the stream cannot be flushed.
+
+    */
+    #[must_use]
+    pub fn new_with_stream<O: Into<Output>>(stream: O) -> Self {
+        let stream = stream.into();
+        let shared = Arc::new(Shared {
+            lines: Mutex::new(Vec::new()),
+            still_spinning: AtomicBool::new(true),
+            drawn: AtomicUsize::new(0),
+            animated: stream.is_terminal(),
+            stream,
+        });
+        let handle = shared.animated.then(|| {
+            thread::spawn({
+                let shared = Arc::clone(&shared);
+                move || {
+                    let _cursor = HiddenCursor::hide(&shared.stream);
+                    loop {
+                        // Read the flag before drawing, so the final draw shows every finished line.
+                        let spinning = shared.still_spinning.load(Ordering::Relaxed);
+                        let next_frame = shared.draw(&mut lock(&shared.lines));
+                        if !spinning {
+                            break;
+                        }
+                        let now = Instant::now();
+                        let wait =
+                            next_frame.map_or(MAX_TICK, |next| next.saturating_duration_since(now));
+                        // `stop` unparks the thread, so the final draw doesn't wait for the tick to end.
+                        thread::park_timeout(wait.min(MAX_TICK));
+                    }
+                }
+            })
+        });
+
+        Self {
+            thread_handle: handle,
+            shared,
+        }
+    }
+
+    /**
+    Add a new spinning line below the existing ones.
+
+    # Arguments
+
+    * `spinner_type` - The spinner to use.
+    * `msg` - The message to display.
+    * `color` - The color of the spinner.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut multi = MultiSpinner::new();
+    let jobs: Vec<_> = (1..=3)
+        .map(|i| multi.add(spinners::Dots, format!("Job {i}..."), Color::Cyan))
+        .collect();
+    sleep(Duration::from_millis(800));
+    for (i, job) in jobs.iter().enumerate() {
+        job.success(&format!("Job {} done!", i + 1));
+    }
+    multi.stop();
+    ```
+
+    */
+    #[must_use = "Dropping the handle clears the line"]
+    pub fn add<S, T, U>(&self, spinner_type: S, msg: T, color: U) -> SpinnerLine
+    where
+        S: Into<SpinnerFrames>,
+        T: Into<Cow<'static, str>>,
+        U: Into<Option<Color>>,
+    {
+        let mut state = SpinnerState::new(
+            spinner_type.into(),
+            msg.into(),
+            color.into(),
+            &self.shared.stream,
+        );
+        let next_frame = Instant::now() + state.interval();
+        let mut lines = lock(&self.shared.lines);
+        if !self.shared.animated {
+            state.draw(&self.shared.stream);
+        }
+        lines.push(Line {
+            state,
+            next_frame,
+            status: LineStatus::Spinning,
+            drop_behavior: DropBehavior::default(),
+        });
+        SpinnerLine {
+            shared: Arc::clone(&self.shared),
+            index: lines.len() - 1,
+        }
+    }
+
+    /**
+    Prints a line above the spinners.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut multi = MultiSpinner::new();
+    let line = multi.add(spinners::Dots, "Compiling...", None);
+    sleep(Duration::from_millis(800));
+    multi.println("Compiling spinoff v0.8.0");
+    sleep(Duration::from_millis(800));
+    line.success("Compiled!");
+    multi.stop();
+    ```
+
+    */
+    pub fn println(&self, msg: &str) {
+        self.shared.println(msg);
+    }
+
+    /**
+    Removes the spinners from the screen while `f` runs, then draws them again below its output.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut multi = MultiSpinner::new();
+    let line = multi.add(spinners::Dots, "Compiling...", None);
+    sleep(Duration::from_millis(800));
+    multi.suspend(|| {
+        println!("warning: unused variable");
+        println!("warning: unused import");
+    });
+    sleep(Duration::from_millis(800));
+    line.success("Compiled!");
+    multi.stop();
+    ```
+
+    # Notes
+
+    * The render thread waits until `f` returns, so `f` must not use the multi spinner or its lines.
+    */
+    pub fn suspend<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        self.shared.suspend(|_| f())
+    }
+
+    /**
+    Stop the render thread.
+
+    Lines that are still spinning are persisted with their current message.
+    Stopping an already stopped multi spinner does nothing.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut multi = MultiSpinner::new();
+    let _line = multi.add(spinners::Dots9, "Spinning...", None);
+    sleep(Duration::from_millis(800));
+    multi.stop();
+    ```
+
+    */
+    pub fn stop(&mut self) {
+        self.shared.suspend(|lines| {
+            for line in lines.iter_mut() {
+                if !matches!(line.status, LineStatus::Spinning) {
+                    continue;
+                }
+                line.state.finish(Finish::Stop, &self.shared.stream);
+                line.status = LineStatus::Done;
+            }
+        });
+        self.stop_render_thread();
+    }
+
+    /**
+    Clears every line and stops the render thread.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut multi = MultiSpinner::new();
+    let _first = multi.add(spinners::Grenade, "Clearing...", None);
+    let _second = multi.add(spinners::Grenade, "Clearing too...", None);
+    sleep(Duration::from_millis(800));
+    multi.clear();
+    ```
+
+    */
+    pub fn clear(&mut self) {
+        {
+            let mut lines = lock(&self.shared.lines);
+            for line in lines.iter_mut() {
+                line.status = LineStatus::Done;
+            }
+        }
+        self.stop_render_thread();
+    }
+
+    /// Stop the render thread and wait for it.
+    fn stop_render_thread(&mut self) {
+        self.shared.still_spinning.store(false, Ordering::Relaxed);
+        if let Some(handle) = self.thread_handle.take() {
+            handle.thread().unpark();
+            handle.join().expect("Thread to join.");
+        }
+    }
+}
+
+impl Default for MultiSpinner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for MultiSpinner {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+impl SpinnerLine {
+    /**
+    Stops the line and keeps its message.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut multi = MultiSpinner::new();
+    let line = multi.add(spinners::Dots, "Spinning...", None);
+    sleep(Duration::from_millis(800));
+    line.stop();
+    multi.stop();
+    ```
+
+    */
+    pub fn stop(&self) {
+        self.finish(Finish::Stop);
+    }
+
+    /**
+    Stops the line and replaces it with a message.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut multi = MultiSpinner::new();
+    let line = multi.add(spinners::Dots2, "Hello", None);
+    sleep(Duration::from_millis(800));
+    line.stop_with_message("Bye");
+    multi.stop();
+    ```
+
+    */
+    pub fn stop_with_message(&self, msg: &str) {
+        self.finish(Finish::Message(msg));
+    }
+
+    /**
+    Stops the line and replaces it with a symbol and a message.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut multi = MultiSpinner::new();
+    let line = multi.add(spinners::Mindblown, "Guess what's coming...", None);
+    sleep(Duration::from_millis(800));
+    line.stop_and_persist("🍕", "Pizza!");
+    multi.stop();
+    ```
+
+    */
+    pub fn stop_and_persist(&self, symbol: &str, msg: &str) {
+        self.finish(Finish::Persist(symbol, msg));
+    }
+
+    /**
+    Stops the line and replaces it with a success symbol and a message.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut multi = MultiSpinner::new();
+    let line = multi.add(spinners::Aesthetic, "Trying to load information...", None);
+    sleep(Duration::from_millis(800));
+    line.success("Success!");
+    multi.stop();
+    ```
+
+    */
+    pub fn success(&self, msg: &str) {
+        self.finish(Finish::Outcome(Status::Success, msg));
+    }
+
+    /**
+    Stops the line and replaces it with a failure symbol and a message.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut multi = MultiSpinner::new();
+    let line = multi.add(spinners::BouncingBar, "Executing code...", Color::Green);
+    sleep(Duration::from_millis(800));
+    line.fail("Code failed to compile!");
+    multi.stop();
+    ```
+
+    */
+    pub fn fail(&self, msg: &str) {
+        self.finish(Finish::Outcome(Status::Fail, msg));
+    }
+
+    /**
+    Stops the line and replaces it with a warning symbol and a message.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut multi = MultiSpinner::new();
+    let line = multi.add(spinners::Material, "Measuring network speed...", None);
+    sleep(Duration::from_millis(800));
+    line.warn("You might want to check your internet connection...");
+    multi.stop();
+    ```
+
+    */
+    pub fn warn(&self, msg: &str) {
+        self.finish(Finish::Outcome(Status::Warn, msg));
+    }
+
+    /**
+    Stops the line and replaces it with an info symbol and a message.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut multi = MultiSpinner::new();
+    let line = multi.add(spinners::Dots9, "Loading info message...", None);
+    sleep(Duration::from_millis(800));
+    line.info("This is an info message!");
+    multi.stop();
+    ```
+
+    */
+    pub fn info(&self, msg: &str) {
+        self.finish(Finish::Outcome(Status::Info, msg));
+    }
+
+    /**
+    Updates the spinner, message and color of the line.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut multi = MultiSpinner::new();
+    let line = multi.add(spinners::Dots, "Hello", None);
+    sleep(Duration::from_millis(800));
+    line.update(spinners::Dots2, "World", Color::Red);
+    sleep(Duration::from_millis(800));
+    multi.stop();
+    ```
+
+    */
+    pub fn update<S, T, U>(&self, spinner: S, msg: T, color: U)
+    where
+        S: Into<SpinnerFrames>,
+        T: Into<Cow<'static, str>>,
+        U: Into<Option<Color>>,
+    {
+        let mut lines = lock(&self.shared.lines);
+        let state = &mut lines[self.index].state;
+        state.update(spinner.into(), msg.into(), color.into());
+        self.shared.print_change(state);
+    }
+
+    /**
+    Updates the message of the line.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut multi = MultiSpinner::new();
+    let line = multi.add(spinners::Arc, "Loading...", Color::Magenta);
+    sleep(Duration::from_millis(800));
+    line.update_text("Almost done...");
+    sleep(Duration::from_millis(800));
+    line.success("Done!");
+    multi.stop();
+    ```
+
+    */
+    pub fn update_text<T>(&self, msg: T)
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        let mut lines = lock(&self.shared.lines);
+        let state = &mut lines[self.index].state;
+        state.msg = msg.into();
+        self.shared.print_change(state);
+    }
+
+    /**
+    Sets the total amount of work, e.g. the number of files to upload.
+
+    While a total is set, a progress bar and percentage are shown next to the message,
+    e.g. `⠋ Uploading... [#####-----] 52%`. Pass `None` to go back to just the line.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut multi = MultiSpinner::new();
+    let line = multi.add(spinners::Dots, "Uploading...", None);
+    line.set_total(340);
+    for _ in 0..340 {
+        sleep(Duration::from_millis(2));
+        line.inc(1);
+    }
+    line.success("Uploaded!");
+    multi.stop();
+    ```
+
+    */
+    pub fn set_total<T>(&self, total: T)
+    where
+        T: Into<Option<u64>>,
+    {
+        lock(&self.shared.lines)[self.index].state.total = total.into();
+    }
+
+    /**
+    Sets the amount of work done so far.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut multi = MultiSpinner::new();
+    let line = multi.add(spinners::Dots, "Uploading...", None);
+    line.set_total(100);
+    sleep(Duration::from_millis(400));
+    line.set_position(52);
+    sleep(Duration::from_millis(400));
+    line.success(&format!("Uploaded {} files!", line.position()));
+    multi.stop();
+    ```
+
+    # Notes
+
+    * The progress bar is updated on the next frame, so this is cheap to call often.
+    */
+    pub fn set_position(&self, position: u64) {
+        lock(&self.shared.lines)[self.index].state.position = position;
+    }
+
+    /// Adds `delta` to the amount of work done so far. See [`SpinnerLine::set_total`].
+    pub fn inc(&self, delta: u64) {
+        let state = &mut lock(&self.shared.lines)[self.index].state;
+        state.position = state.position.saturating_add(delta);
+    }
+
+    /// Returns the amount of work done so far.
+    #[must_use]
+    pub fn position(&self) -> u64 {
+        lock(&self.shared.lines)[self.index].state.position
+    }
+
+    /**
+    Shows how long the line has been spinning next to its message, e.g. `⠋ Compiling (12.4s)`.
+
+    The final duration is also added to the line once it is finished.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut multi = MultiSpinner::new();
+    let line = multi.add(spinners::Dots, "Compiling...", None);
+    line.show_elapsed(true);
+    sleep(Duration::from_millis(800));
+    line.success("Compiled!");
+    multi.stop();
+    ```
+
+    */
+    pub fn show_elapsed(&self, show: bool) {
+        lock(&self.shared.lines)[self.index].state.show_elapsed = show;
+    }
+
+    /**
+    Lays out the line with a [`Template`], e.g. `"{spinner} {msg:<20} {bar} {percent:>3}%"`.
+
+    The template is also used once the line is finished with [`SpinnerLine::stop_and_persist`] or one of the `success` type methods.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut multi = MultiSpinner::new();
+    let a = multi.add(spinners::Dots, "a.tar.gz", None);
+    let b = multi.add(spinners::Dots, "b.tar.gz", None);
+    for line in [&a, &b] {
+        line.set_template("{spinner} {msg:<10} [{elapsed:>5}]");
+    }
+    sleep(Duration::from_millis(800));
+    a.success("a.tar.gz");
+    b.success("b.tar.gz");
+    multi.stop();
+    ```
+
+    */
+    pub fn set_template<T: Into<Template>>(&self, template: T) {
+        lock(&self.shared.lines)[self.index].state.template = Some(template.into());
+    }
+
+    /**
+    Sets the [`Style`] of the frame. Its color takes precedence over the color of the line.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut multi = MultiSpinner::new();
+    let line = multi.add(spinners::Dots, "Loading...", Color::BrightCyan);
+    line.set_frame_style(Style::new().bold());
+    sleep(Duration::from_millis(800));
+    multi.stop();
+    ```
+
+    */
+    pub fn set_frame_style(&self, style: Style) {
+        lock(&self.shared.lines)[self.index].state.frame_style = style;
+    }
+
+    /**
+    Sets the [`Style`] of the message, including the message the line is finished with.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut multi = MultiSpinner::new();
+    let line = multi.add(spinners::Dots, "Loading...", None);
+    line.set_message_style(Style::new().dim());
+    sleep(Duration::from_millis(800));
+    multi.stop();
+    ```
+
+    */
+    pub fn set_message_style(&self, style: Style) {
+        lock(&self.shared.lines)[self.index].state.msg_style = style;
+    }
+
+    /**
+    Sets the [`Style`] of the symbols printed by the `success` type methods. By default they are bold.
+    Its color takes precedence over the color of the outcome, e.g. green for [`SpinnerLine::success`].
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut multi = MultiSpinner::new();
+    let line = multi.add(spinners::Dots, "Loading...", None);
+    line.set_symbol_style(Style::new().color(Color::Ansi256(82)));
+    sleep(Duration::from_millis(800));
+    line.success("Loaded!");
+    multi.stop();
+    ```
+
+    */
+    pub fn set_symbol_style(&self, style: Style) {
+        lock(&self.shared.lines)[self.index].state.symbol_style = style;
+    }
+
+    /**
+    Sets the symbols printed by the `success` type methods, overriding the default set with [`set_default_symbols`](crate::set_default_symbols).
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut multi = MultiSpinner::new();
+    let line = multi.add(spinners::Line, "Loading...", None);
+    line.set_symbols(Symbols::ASCII);
+    sleep(Duration::from_millis(800));
+    line.success("Loaded!");
+    multi.stop();
+    ```
+
+    */
+    pub fn set_symbols(&self, symbols: Symbols) {
+        lock(&self.shared.lines)[self.index].state.symbols = symbols;
+    }
+
+    /**
+    Sets the streams the lines printed by the `success` type methods go to, instead of the multi spinner's stream.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut multi = MultiSpinner::new();
+    let line = multi.add(spinners::Dots, "Linking...", None);
+    line.set_routing(Routing::split());
+    sleep(Duration::from_millis(800));
+    line.fail("Printed to stderr!");
+    multi.stop();
+    ```
+
+    */
+    pub fn set_routing(&self, routing: Routing) {
+        lock(&self.shared.lines)[self.index].state.routing = routing;
+    }
+
+    /**
+    Sets whether the line prints colors and styles, overriding the default set with [`set_default_color_policy`](crate::set_default_color_policy).
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut multi = MultiSpinner::new();
+    let line = multi.add(spinners::Dots, "Loading...", Color::Blue);
+    line.set_color_policy(ColorPolicy::Never);
+    sleep(Duration::from_millis(800));
+    line.success("No colors here!");
+    multi.stop();
+    ```
+
+    */
+    pub fn set_color_policy(&self, policy: ColorPolicy) {
+        lock(&self.shared.lines)[self.index]
+            .state
+            .set_color_policy(policy, &self.shared.stream);
+    }
+
+    /**
+    Sets what happens to the line when its handle is dropped while it is still spinning.
+    By default, the line is cleared.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut multi = MultiSpinner::new();
+    let line = multi.add(spinners::Dots, "Compiling...", None);
+    line.set_drop_behavior(DropBehavior::Persist);
+    sleep(Duration::from_millis(800));
+    drop(line);
+    multi.stop();
+    ```
+
+    */
+    pub fn set_drop_behavior(&self, behavior: DropBehavior) {
+        lock(&self.shared.lines)[self.index].drop_behavior = behavior;
+    }
+
+    /**
+    Returns the time since the line was added.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut multi = MultiSpinner::new();
+    let line = multi.add(spinners::Dots, "Compiling...", None);
+    sleep(Duration::from_millis(800));
+    line.success(&format!("Compiled in {}ms!", line.elapsed().as_millis()));
+    multi.stop();
+    ```
+
+    */
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        lock(&self.shared.lines)[self.index].state.started.elapsed()
+    }
+
+    /**
+    Prints a line above the spinners of the multi spinner this line belongs to.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut multi = MultiSpinner::new();
+    let line = multi.add(spinners::Dots, "Downloading...", None);
+    sleep(Duration::from_millis(800));
+    line.println("Downloaded 3 of 5 files");
+    sleep(Duration::from_millis(800));
+    line.success("Downloaded!");
+    multi.stop();
+    ```
+
+    */
+    pub fn println(&self, msg: &str) {
+        self.shared.println(msg);
+    }
+
+    /**
+    Removes the spinners of the multi spinner this line belongs to from the screen while `f` runs,
+    then draws them again below its output.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut multi = MultiSpinner::new();
+    let line = multi.add(spinners::Dots, "Downloading...", None);
+    sleep(Duration::from_millis(800));
+    line.suspend(|| println!("Downloaded 3 of 5 files"));
+    sleep(Duration::from_millis(800));
+    line.success("Downloaded!");
+    multi.stop();
+    ```
+
+    # Notes
+
+    * The render thread waits until `f` returns, so `f` must not use the multi spinner or its lines.
+    */
+    pub fn suspend<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        self.shared.suspend(|_| f())
+    }
+
+    /**
+    Removes the line from the multi spinner.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let mut multi = MultiSpinner::new();
+    let line = multi.add(spinners::Grenade, "Clearing...", None);
+    sleep(Duration::from_millis(800));
+    line.clear();
+    multi.stop();
+    ```
+
+    */
+    pub fn clear(&self) {
+        self.finish(Finish::Clear);
+    }
+
+    /// Replace a spinning line with what is left in its place, printed above the lines that are still spinning.
+    fn finish(&self, finish: Finish<'_>) {
+        self.shared.suspend(|lines| {
+            let line = &mut lines[self.index];
+            // The line was already finished, possibly by `MultiSpinner::stop`.
+            if !matches!(line.status, LineStatus::Spinning) {
+                return;
+            }
+            line.state.finish(finish, &self.shared.stream);
+            line.status = LineStatus::Done;
+        });
+    }
+}
+
+impl Drop for SpinnerLine {
+    fn drop(&mut self) {
+        let behavior = lock(&self.shared.lines)[self.index].drop_behavior;
+        self.finish(Finish::dropped(behavior));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::spinners::SpinnerFrames;
+
+    fn captured() -> (Arc<Mutex<Vec<u8>>>, MultiSpinner) {
+        let buffer = Arc::new(Mutex::new(Vec::new()));
+        let multi = MultiSpinner::new_with_stream(Output::Writer(buffer.clone()));
+        (buffer, multi)
+    }
+
+    fn output(buffer: &Mutex<Vec<u8>>) -> String {
+        String::from_utf8(lock(buffer).clone()).unwrap()
+    }
+
+    #[test]
+    fn finishing_twice_prints_once() {
+        let (buffer, mut multi) = captured();
+        let line = multi.add(SpinnerFrames::new(["-"], 100), "Working", None);
+        line.set_symbols(Symbols::ASCII);
+        line.success("ok1");
+        line.success("ok2");
+        line.fail("bad");
+        multi.stop();
+        assert_eq!(output(&buffer), "Working\n[ok] ok1\n");
+    }
+
+    #[test]
+    fn finishing_after_stop_does_nothing() {
+        let (buffer, mut multi) = captured();
+        let line = multi.add(SpinnerFrames::new(["-"], 100), "Working", None);
+        multi.stop();
+        line.fail("too late");
+        assert_eq!(output(&buffer), "Working\n");
+    }
+}
@@ -1,18 +1,266 @@
-#[allow(unused_imports)]
 use once_cell::sync::Lazy;
 use paste::paste;
+use std::borrow::Cow;
+#[cfg(feature = "json")]
+use std::collections::HashMap;
+#[cfg(feature = "json")]
+use std::io::Read;
 
 /// A Struct that contains the data for a spinner.
-/// Frames is a Vec of &str, each &str is a frame of the spinner.
+/// Frames is a Vec of strings, each string is a frame of the spinner.
+/// Frames can be borrowed `'static` strings or owned ones built at runtime.
 /// Interval is the number of milliseconds to wait before moving to the next frame.
+/// Durations optionally overrides the interval for each frame, in milliseconds;
+/// frames past its end use the interval.
+/// Playback is the order in which the frames are shown.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "json", derive(serde::Deserialize))]
 pub struct SpinnerFrames {
-    pub frames: Vec<&'static str>,
+    #[cfg_attr(feature = "json", serde(deserialize_with = "non_empty"))]
+    pub frames: Vec<Cow<'static, str>>,
     pub interval: u16,
+    #[cfg_attr(feature = "json", serde(default))]
+    pub durations: Vec<u16>,
+    #[cfg_attr(feature = "json", serde(default))]
+    pub playback: Playback,
 }
 
+/// The order in which the frames of a spinner are shown.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+#[cfg_attr(
+    feature = "json",
+    derive(serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[non_exhaustive]
+pub enum Playback {
+    /// First to last, then start over.
+    #[default]
+    Loop,
+    /// First to last, then back to the first, without showing the last and first frames twice.
+    PingPong,
+    /// Last to first, then start over.
+    Reverse,
+    /// A random frame every time, never the same one twice in a row.
+    Random,
+    /// First to last, then stay on the last frame.
+    Once,
+}
+
+impl SpinnerFrames {
+    /**
+    Create spinner frames from any strings, such as frames read from a config file.
+
+    # Arguments
+
+    * `frames` - The frames of the spinner
+    * `interval` - The time (in milliseconds) that will pass between frames
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let frames = (1..=3).map(|n| "=".repeat(n));
+    let mut sp = Spinner::new(spinners::SpinnerFrames::new(frames, 100), "Loading...", None);
+    sleep(Duration::from_millis(800));
+    sp.stop();
+    ```
+
+    # Panics
+
+    * If `frames` is empty.
+    */
+    pub fn new<I, S>(frames: I, interval: u16) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Cow<'static, str>>,
+    {
+        let frames: Vec<_> = frames.into_iter().map(Into::into).collect();
+        assert!(!frames.is_empty(), "A spinner needs at least one frame.");
+        Self {
+            frames,
+            interval,
+            durations: Vec::new(),
+            playback: Playback::Loop,
+        }
+    }
+
+    /**
+    Change the order in which the frames are shown.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use spinoff::spinners::{Playback, SpinnerFrames};
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let frames = SpinnerFrames::from(spinners::Arrow).with_playback(Playback::PingPong);
+    let mut sp = Spinner::new(frames, "Bouncing...", None);
+    sleep(Duration::from_millis(800));
+    sp.stop();
+    ```
+    */
+    #[must_use]
+    pub fn with_playback(mut self, playback: Playback) -> Self {
+        self.playback = playback;
+        self
+    }
+
+    /// How long frame number `index` is shown, in milliseconds.
+    #[must_use]
+    pub fn duration(&self, index: usize) -> u16 {
+        self.durations.get(index).copied().unwrap_or(self.interval)
+    }
+
+    /**
+    Parse a spinner in the [cli-spinners](https://github.com/sindresorhus/cli-spinners) format,
+    e.g. `{"interval": 80, "frames": ["-", "\\", "|", "/"]}`.
+    An optional `durations` array sets the duration of each frame, like in [`spinner!`](crate::spinner!),
+    and an optional `playback` key one of the [`Playback`] modes in snake case, e.g. `"ping_pong"`.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let frames = spinners::SpinnerFrames::from_json(r#"{"interval": 100, "frames": [">", ">>", ">>>"]}"#).unwrap();
+    let mut sp = Spinner::new(frames, "Loading...", None);
+    sleep(Duration::from_millis(800));
+    sp.stop();
+    ```
+
+    # Errors
+
+    * If `json` is not valid JSON, or is missing the `interval` or `frames` keys.
+    * If `frames` is empty.
+    */
+    #[cfg(feature = "json")]
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+/**
+Load a whole file of spinners in the [cli-spinners](https://github.com/sindresorhus/cli-spinners) format,
+an object mapping names to spinners, e.g. `{"arrows": {"interval": 100, "frames": ["←", "↑", "→", "↓"]}}`.
+
+# Example
+
+```
+# use spinoff::*;
+# use std::thread::sleep;
+# use std::time::Duration;
+#
+let json = r#"{
+    "arrows": {"interval": 100, "frames": ["←", "↑", "→", "↓"]},
+    "pulse": {"interval": 120, "frames": ["·", "•", "●", "•"]}
+}"#;
+// Or `std::fs::File::open("spinners.json")?`.
+let mut spinners = spinners::load_json(json.as_bytes()).unwrap();
+let mut sp = Spinner::new(spinners.remove("arrows").unwrap(), "Loading...", None);
+sleep(Duration::from_millis(800));
+sp.stop();
+```
+
+# Errors
+
+* If the JSON can't be read or parsed, or any of the spinners is invalid, see [`SpinnerFrames::from_json`].
+*/
+#[cfg(feature = "json")]
+pub fn load_json<R: Read>(reader: R) -> Result<HashMap<String, SpinnerFrames>, serde_json::Error> {
+    serde_json::from_reader(reader)
+}
+
+/// Reject spinners without frames, which could never be drawn.
+#[cfg(feature = "json")]
+fn non_empty<'de, D>(deserializer: D) -> Result<Vec<Cow<'static, str>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::Error;
+    use serde::Deserialize;
+
+    let frames = Vec::<Cow<'static, str>>::deserialize(deserializer)?;
+    if frames.is_empty() {
+        return Err(D::Error::custom("a spinner needs at least one frame"));
+    }
+    Ok(frames)
+}
+
+/**
+Look up a spinner by the name of its feature, e.g. `"dots9"` or `"simple_dots_scrolling"`.
+Returns `None` if there is no such spinner, or its feature is disabled.
+
+# Example
+
+```
+# use spinoff::*;
+# use std::thread::sleep;
+# use std::time::Duration;
+#
+// E.g. the value of a `--spinner` flag.
+let name = "dots9";
+let frames = spinners::by_name(name).unwrap_or_else(|| spinners::Dots.into());
+let mut sp = Spinner::new(frames, "Loading...", None);
+sleep(Duration::from_millis(800));
+sp.stop();
+```
+*/
+#[must_use]
+pub fn by_name(name: &str) -> Option<SpinnerFrames> {
+    REGISTRY
+        .iter()
+        .find(|(spinner, _)| *spinner == name)
+        .map(|(_, frames)| SpinnerFrames::clone(frames))
+}
+
+/**
+Every spinner enabled by a feature, with its name.
+
+# Example
+
+```
+# use spinoff::spinners;
+#
+for (name, frames) in spinners::all() {
+    println!("{name}: {} ({}ms)", frames.frames.join(" "), frames.interval);
+}
+```
+*/
+pub fn all() -> impl Iterator<Item = (&'static str, SpinnerFrames)> {
+    REGISTRY
+        .iter()
+        .map(|(name, frames)| (*name, SpinnerFrames::clone(frames)))
+}
+
+/// Defines every spinner from a list of `(name, [frames], interval[, playback])` entries:
+/// a unit struct, its conversion to [`SpinnerFrames`], and its place in the registry,
+/// each behind the feature of the same name.
 macro_rules! spinner_frames {
-  ( $name:expr, [ $( $frame:expr ),* ], $interval:expr ) => {
+  ( $( ( $name:literal, [ $( $frame:expr ),* $(,)? ], $interval:expr $(, $playback:ident )? $(,)? ) ),* $(,)? ) => {
+      $( spinner_frames!(@spinner $name, [ $( $frame ),* ], $interval $(, $playback )?); )*
+
+      paste! {
+          /// The spinners enabled by features, by name.
+          static REGISTRY: &[(&str, &Lazy<SpinnerFrames>)] = &[
+              $(
+                  #[cfg(feature = $name)]
+                  ($name, &[< $name:upper >]),
+              )*
+          ];
+      }
+  };
+  ( @spinner $name:expr, [ $( $frame:expr ),* ], $interval:expr ) => {
+      spinner_frames!(@spinner $name, [ $( $frame ),* ], $interval, Loop);
+  };
+  ( @spinner $name:expr, [ $( $frame:expr ),* ], $interval:expr, $playback:ident ) => {
       paste! {
           #[cfg(feature = $name)]
           pub struct [< $name:camel >];
@@ -29,565 +277,531 @@ macro_rules! spinner_frames {
           static [< $name:upper >]: Lazy<SpinnerFrames>
               = Lazy::new(|| SpinnerFrames {
                   interval: $interval,
-                  frames: vec![$($frame),*]
+                  frames: vec![$(Cow::Borrowed($frame)),*],
+                  durations: Vec::new(),
+                  playback: Playback::$playback,
           });
       }
   };
 }
 
-spinner_frames!(
-    "dots",
-    ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"],
-    80
-);
-
-spinner_frames!("dots2", ["⣾", "⣽", "⣻", "⢿", "⡿", "⣟", "⣯", "⣷"], 80);
-
-spinner_frames!(
-    "dots3",
-    ["⠋", "⠙", "⠚", "⠞", "⠖", "⠦", "⠴", "⠲", "⠳", "⠓"],
-    80
-);
-
-spinner_frames!(
-    "dots4",
-    [
-        "⠄", "⠆", "⠇", "⠋", "⠙", "⠸", "⠰", "⠠", "⠰", "⠸", "⠙", "⠋", "⠇", "⠆"
-    ],
-    80
-);
-
-spinner_frames!(
-    "dots5",
-    [
-        "⠋", "⠙", "⠚", "⠒", "⠂", "⠂", "⠒", "⠲", "⠴", "⠦", "⠖", "⠒", "⠐", "⠐", "⠒", "⠓", "⠋"
-    ],
-    80
-);
-
-spinner_frames!(
-    "dots6",
-    [
-        "⠁", "⠉", "⠙", "⠚", "⠒", "⠂", "⠂", "⠒", "⠲", "⠴", "⠤", "⠄", "⠄", "⠤", "⠴", "⠲", "⠒", "⠂",
-        "⠂", "⠒", "⠚", "⠙", "⠉", "⠁"
-    ],
-    80
-);
-
-spinner_frames!(
-    "dots7",
-    [
-        "⠈", "⠉", "⠋", "⠓", "⠒", "⠐", "⠐", "⠒", "⠖", "⠦", "⠤", "⠠", "⠠", "⠤", "⠦", "⠖", "⠒", "⠐",
-        "⠐", "⠒", "⠓", "⠋", "⠉", "⠈"
-    ],
-    80
-);
-spinner_frames!(
-    "dots8",
-    [
-        "⠁", "⠁", "⠉", "⠙", "⠚", "⠒", "⠂", "⠂", "⠒", "⠲", "⠴", "⠤", "⠄", "⠄", "⠤", "⠠", "⠠", "⠤",
-        "⠦", "⠖", "⠒", "⠐", "⠐", "⠒", "⠓", "⠋", "⠉", "⠈", "⠈"
-    ],
-    80
-);
-spinner_frames!("dots9", ["⢹", "⢺", "⢼", "⣸", "⣇", "⡧", "⡗", "⡏"], 80);
-spinner_frames!("dots10", ["⢄", "⢂", "⢁", "⡁", "⡈", "⡐", "⡠"], 80);
-spinner_frames!("dots11", ["⠁", "⠂", "⠄", "⡀", "⢀", "⠠", "⠐", "⠈"], 80);
-spinner_frames!(
-    "dots12",
-    [
-        "⢀⠀", "⡀⠀", "⠄⠀", "⢂⠀", "⡂⠀", "⠅⠀", "⢃⠀", "⡃⠀", "⠍⠀", "⢋⠀", "⡋⠀", "⠍⠁", "⢋⠁", "⡋⠁", "⠍⠉",
-        "⠋⠉", "⠋⠉", "⠉⠙", "⠉⠙", "⠉⠩", "⠈⢙", "⠈⡙", "⢈⠩", "⡀⢙", "⠄⡙", "⢂⠩", "⡂⢘", "⠅⡘", "⢃⠨", "⡃⢐",
-        "⠍⡐", "⢋⠠", "⡋⢀", "⠍⡁", "⢋⠁", "⡋⠁", "⠍⠉", "⠋⠉", "⠋⠉", "⠉⠙", "⠉⠙", "⠉⠩", "⠈⢙", "⠈⡙", "⠈⠩",
-        "⠀⢙", "⠀⡙", "⠀⠩", "⠀⢘", "⠀⡘", "⠀⠨", "⠀⢐", "⠀⡐", "⠀⠠", "⠀⢀", "⠀⡀"
-    ],
-    80
-);
-spinner_frames!(
-    "dots8bit",
-    [
-        "⠀", "⠁", "⠂", "⠃", "⠄", "⠅", "⠆", "⠇", "⡀", "⡁", "⡂", "⡃", "⡄", "⡅", "⡆", "⡇", "⠈", "⠉",
-        "⠊", "⠋", "⠌", "⠍", "⠎", "⠏", "⡈", "⡉", "⡊", "⡋", "⡌", "⡍", "⡎", "⡏", "⠐", "⠑", "⠒", "⠓",
-        "⠔", "⠕", "⠖", "⠗", "⡐", "⡑", "⡒", "⡓", "⡔", "⡕", "⡖", "⡗", "⠘", "⠙", "⠚", "⠛", "⠜", "⠝",
-        "⠞", "⠟", "⡘", "⡙", "⡚", "⡛", "⡜", "⡝", "⡞", "⡟", "⠠", "⠡", "⠢", "⠣", "⠤", "⠥", "⠦", "⠧",
-        "⡠", "⡡", "⡢", "⡣", "⡤", "⡥", "⡦", "⡧", "⠨", "⠩", "⠪", "⠫", "⠬", "⠭", "⠮", "⠯", "⡨", "⡩",
-        "⡪", "⡫", "⡬", "⡭", "⡮", "⡯", "⠰", "⠱", "⠲", "⠳", "⠴", "⠵", "⠶", "⠷", "⡰", "⡱", "⡲", "⡳",
-        "⡴", "⡵", "⡶", "⡷", "⠸", "⠹", "⠺", "⠻", "⠼", "⠽", "⠾", "⠿", "⡸", "⡹", "⡺", "⡻", "⡼", "⡽",
-        "⡾", "⡿", "⢀", "⢁", "⢂", "⢃", "⢄", "⢅", "⢆", "⢇", "⣀", "⣁", "⣂", "⣃", "⣄", "⣅", "⣆", "⣇",
-        "⢈", "⢉", "⢊", "⢋", "⢌", "⢍", "⢎", "⢏", "⣈", "⣉", "⣊", "⣋", "⣌", "⣍", "⣎", "⣏", "⢐", "⢑",
-        "⢒", "⢓", "⢔", "⢕", "⢖", "⢗", "⣐", "⣑", "⣒", "⣓", "⣔", "⣕", "⣖", "⣗", "⢘", "⢙", "⢚", "⢛",
-        "⢜", "⢝", "⢞", "⢟", "⣘", "⣙", "⣚", "⣛", "⣜", "⣝", "⣞", "⣟", "⢠", "⢡", "⢢", "⢣", "⢤", "⢥",
-        "⢦", "⢧", "⣠", "⣡", "⣢", "⣣", "⣤", "⣥", "⣦", "⣧", "⢨", "⢩", "⢪", "⢫", "⢬", "⢭", "⢮", "⢯",
-        "⣨", "⣩", "⣪", "⣫", "⣬", "⣭", "⣮", "⣯", "⢰", "⢱", "⢲", "⢳", "⢴", "⢵", "⢶", "⢷", "⣰", "⣱",
-        "⣲", "⣳", "⣴", "⣵", "⣶", "⣷", "⢸", "⢹", "⢺", "⢻", "⢼", "⢽", "⢾", "⢿", "⣸", "⣹", "⣺", "⣻",
-        "⣼", "⣽", "⣾", "⣿"
-    ],
-    80
-);
-
-spinner_frames!("line", ["-", "\\", "|", "/"], 130);
-
-spinner_frames!("line2", ["⠂", "-", "–", "—", "–", "-"], 100);
+spinner_frames! {
+    (
+        "dots",
+        ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"],
+        80
+    ),
+    ("dots2", ["⣾", "⣽", "⣻", "⢿", "⡿", "⣟", "⣯", "⣷"], 80),
+    (
+        "dots3",
+        ["⠋", "⠙", "⠚", "⠞", "⠖", "⠦", "⠴", "⠲", "⠳", "⠓"],
+        80
+    ),
+    (
+        "dots4",
+        ["⠄", "⠆", "⠇", "⠋", "⠙", "⠸", "⠰", "⠠"],
+        80,
+        PingPong
+    ),
+    (
+        "dots5",
+        [
+            "⠋", "⠙", "⠚", "⠒", "⠂", "⠂", "⠒", "⠲", "⠴", "⠦", "⠖", "⠒", "⠐", "⠐", "⠒", "⠓", "⠋"
+        ],
+        80
+    ),
+    (
+        "dots6",
+        [
+            "⠁", "⠉", "⠙", "⠚", "⠒", "⠂", "⠂", "⠒", "⠲", "⠴", "⠤", "⠄", "⠄", "⠤", "⠴", "⠲", "⠒", "⠂",
+            "⠂", "⠒", "⠚", "⠙", "⠉", "⠁"
+        ],
+        80
+    ),
+    (
+        "dots7",
+        [
+            "⠈", "⠉", "⠋", "⠓", "⠒", "⠐", "⠐", "⠒", "⠖", "⠦", "⠤", "⠠", "⠠", "⠤", "⠦", "⠖", "⠒", "⠐",
+            "⠐", "⠒", "⠓", "⠋", "⠉", "⠈"
+        ],
+        80
+    ),
+    (
+        "dots8",
+        [
+            "⠁", "⠁", "⠉", "⠙", "⠚", "⠒", "⠂", "⠂", "⠒", "⠲", "⠴", "⠤", "⠄", "⠄", "⠤", "⠠", "⠠", "⠤",
+            "⠦", "⠖", "⠒", "⠐", "⠐", "⠒", "⠓", "⠋", "⠉", "⠈", "⠈"
+        ],
+        80
+    ),
+    ("dots9", ["⢹", "⢺", "⢼", "⣸", "⣇", "⡧", "⡗", "⡏"], 80),
+    ("dots10", ["⢄", "⢂", "⢁", "⡁", "⡈", "⡐", "⡠"], 80),
+    ("dots11", ["⠁", "⠂", "⠄", "⡀", "⢀", "⠠", "⠐", "⠈"], 80),
+    (
+        "dots12",
+        [
+            "⢀⠀", "⡀⠀", "⠄⠀", "⢂⠀", "⡂⠀", "⠅⠀", "⢃⠀", "⡃⠀", "⠍⠀", "⢋⠀", "⡋⠀", "⠍⠁", "⢋⠁", "⡋⠁", "⠍⠉",
+            "⠋⠉", "⠋⠉", "⠉⠙", "⠉⠙", "⠉⠩", "⠈⢙", "⠈⡙", "⢈⠩", "⡀⢙", "⠄⡙", "⢂⠩", "⡂⢘", "⠅⡘", "⢃⠨", "⡃⢐",
+            "⠍⡐", "⢋⠠", "⡋⢀", "⠍⡁", "⢋⠁", "⡋⠁", "⠍⠉", "⠋⠉", "⠋⠉", "⠉⠙", "⠉⠙", "⠉⠩", "⠈⢙", "⠈⡙", "⠈⠩",
+            "⠀⢙", "⠀⡙", "⠀⠩", "⠀⢘", "⠀⡘", "⠀⠨", "⠀⢐", "⠀⡐", "⠀⠠", "⠀⢀", "⠀⡀"
+        ],
+        80
+    ),
+    (
+        "dots8bit",
+        [
+            "⠀", "⠁", "⠂", "⠃", "⠄", "⠅", "⠆", "⠇", "⡀", "⡁", "⡂", "⡃", "⡄", "⡅", "⡆", "⡇", "⠈", "⠉",
+            "⠊", "⠋", "⠌", "⠍", "⠎", "⠏", "⡈", "⡉", "⡊", "⡋", "⡌", "⡍", "⡎", "⡏", "⠐", "⠑", "⠒", "⠓",
+            "⠔", "⠕", "⠖", "⠗", "⡐", "⡑", "⡒", "⡓", "⡔", "⡕", "⡖", "⡗", "⠘", "⠙", "⠚", "⠛", "⠜", "⠝",
+            "⠞", "⠟", "⡘", "⡙", "⡚", "⡛", "⡜", "⡝", "⡞", "⡟", "⠠", "⠡", "⠢", "⠣", "⠤", "⠥", "⠦", "⠧",
+            "⡠", "⡡", "⡢", "⡣", "⡤", "⡥", "⡦", "⡧", "⠨", "⠩", "⠪", "⠫", "⠬", "⠭", "⠮", "⠯", "⡨", "⡩",
+            "⡪", "⡫", "⡬", "⡭", "⡮", "⡯", "⠰", "⠱", "⠲", "⠳", "⠴", "⠵", "⠶", "⠷", "⡰", "⡱", "⡲", "⡳",
+            "⡴", "⡵", "⡶", "⡷", "⠸", "⠹", "⠺", "⠻", "⠼", "⠽", "⠾", "⠿", "⡸", "⡹", "⡺", "⡻", "⡼", "⡽",
+            "⡾", "⡿", "⢀", "⢁", "⢂", "⢃", "⢄", "⢅", "⢆", "⢇", "⣀", "⣁", "⣂", "⣃", "⣄", "⣅", "⣆", "⣇",
+            "⢈", "⢉", "⢊", "⢋", "⢌", "⢍", "⢎", "⢏", "⣈", "⣉", "⣊", "⣋", "⣌", "⣍", "⣎", "⣏", "⢐", "⢑",
+            "⢒", "⢓", "⢔", "⢕", "⢖", "⢗", "⣐", "⣑", "⣒", "⣓", "⣔", "⣕", "⣖", "⣗", "⢘", "⢙", "⢚", "⢛",
+            "⢜", "⢝", "⢞", "⢟", "⣘", "⣙", "⣚", "⣛", "⣜", "⣝", "⣞", "⣟", "⢠", "⢡", "⢢", "⢣", "⢤", "⢥",
+            "⢦", "⢧", "⣠", "⣡", "⣢", "⣣", "⣤", "⣥", "⣦", "⣧", "⢨", "⢩", "⢪", "⢫", "⢬", "⢭", "⢮", "⢯",
+            "⣨", "⣩", "⣪", "⣫", "⣬", "⣭", "⣮", "⣯", "⢰", "⢱", "⢲", "⢳", "⢴", "⢵", "⢶", "⢷", "⣰", "⣱",
+            "⣲", "⣳", "⣴", "⣵", "⣶", "⣷", "⢸", "⢹", "⢺", "⢻", "⢼", "⢽", "⢾", "⢿", "⣸", "⣹", "⣺", "⣻",
+            "⣼", "⣽", "⣾", "⣿"
+        ],
+        80
+    ),
+    ("line", ["-", "\\", "|", "/"], 130),
+    ("line2", ["⠂", "-", "–", "—", "–", "-"], 100),
+    ("pipe", ["┤", "┘", "┴", "└", "├", "┌", "┬", "┐"], 100),
+    ("simple_dots", [".  ", ".. ", "...", "   "], 400),
+    (
+        "simple_dots_scrolling",
+        [".  ", ".. ", "...", " ..", "  .", "   "],
+        200
+    ),
+    ("star", ["✶", "✸", "✹", "✺", "✹", "✷"], 70),
+    ("star2", ["+", "x", "*"], 80),
+    (
+        "flip",
+        ["_", "_", "_", "-", "`", "`", "'", "´", "-", "_", "_", "_"],
+        70
+    ),
+    ("hamburger", ["☱", "☲", "☴"], 100),
+    (
+        "grow_vertical",
+        ["▁", "▃", "▄", "▅", "▆", "▇", "▆", "▅", "▄", "▃"],
+        120
+    ),
+    (
+        "grow_horizontal",
+        ["▏", "▎", "▍", "▌", "▋", "▊", "▉", "▊", "▋", "▌", "▍", "▎"],
+        120
+    ),
+    ("balloon", [" ", ".", "o", "O", "@", "*", " "], 140),
+    ("balloon2", [".", "o", "O", "°", "O", "o", "."], 120),
+    ("noise", ["▓", "▒", "░"], 100),
+    ("bounce", ["⠁", "⠂", "⠄", "⠂"], 120),
+    ("box_bounce", ["▖", "▘", "▝", "▗"], 120),
+    ("box_bounce2", ["▌", "▀", "▐", "▄"], 100),
+    ("triangle", ["◢", "◣", "◤", "◥"], 50),
+    ("arc", ["◜", "◠", "◝", "◞", "◡", "◟"], 100),
+    ("circle", ["◡", "⊙", "◠"], 120),
+    ("square_corners", ["◰", "◳", "◲", "◱"], 180),
+    ("circle_quarters", ["◴", "◷", "◶", "◵"], 120),
+    ("circle_halves", ["◐", "◓", "◑", "◒"], 50),
+    ("squish", ["╫", "╪"], 100),
+    ("toggle", ["⊶", "⊷"], 250),
+    ("toggle2", ["▫", "▪"], 80),
+    ("toggle3", ["□", "■"], 120),
+    ("toggle4", ["■", "□", "▪", "▫"], 100),
+    ("toggle5", ["▮", "▯"], 100),
+    ("toggle6", ["ဝ", "၀"], 300),
+    ("toggle7", ["⦾", "⦿"], 80),
+    ("toggle8", ["◍", "◌"], 100),
+    ("toggle9", ["◉", "◎"], 100),
+    ("toggle10", ["㊂", "㊀", "㊁"], 100),
+    ("toggle11", ["⧇", "⧆"], 50),
+    ("toggle12", ["☗", "☖"], 120),
+    ("toggle13", ["=", "*", "-"], 80),
+    ("arrow", ["←", "↖", "↑", "↗", "→", "↘", "↓", "↙"], 100),
+    (
+        "arrow2",
+        ["⬆️ ", "↗️ ", "➡️ ", "↘️ ", "⬇️ ", "↙️ ", "⬅️ ", "↖️ "],
+        80
+    ),
+    (
+        "arrow3",
+        ["▹▹▹▹▹", "▸▹▹▹▹", "▹▸▹▹▹", "▹▹▸▹▹", "▹▹▹▸▹", "▹▹▹▹▸"],
+        120
+    ),
+    (
+        "bouncing_bar",
+        [
+            "[    ]", "[=   ]", "[==  ]", "[=== ]", "[ ===]", "[  ==]", "[   =]", "[    ]", "[   =]",
+            "[  ==]", "[ ===]", "[====]", "[=== ]", "[==  ]", "[=   ]"
+        ],
+        80
+    ),
+    (
+        "bouncing_ball",
+        [
+            "( ●    )",
+            "(  ●   )",
+            "(   ●  )",
+            "(    ● )",
+            "(     ●)",
+            "(    ● )",
+            "(   ●  )",
+            "(  ●   )",
+            "( ●    )",
+            "(●     )"
+        ],
+        80
+    ),
+    ("smiley", ["😄 ", "😝 "], 200),
+    ("monkey", ["🙈 ", "🙈 ", "🙉 ", "🙊 "], 300),
+    ("hearts", ["💛 ", "💙 ", "💜 ", "💚 ", "❤️ "], 100),
+    (
+        "clock",
+        ["🕛 ", "🕐 ", "🕑 ", "🕒 ", "🕓 ", "🕔 ", "🕕 "],
+        100
+    ),
+    (
+        "material",
+        [
+            "█▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁",
+            "██▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁",
+            "███▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁",
+            "████▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁",
+            "██████▁▁▁▁▁▁▁▁▁▁▁▁▁▁",
+            "██████▁▁▁▁▁▁▁▁▁▁▁▁▁▁",
+            "███████▁▁▁▁▁▁▁▁▁▁▁▁▁",
+            "████████▁▁▁▁▁▁▁▁▁▁▁▁",
+            "█████████▁▁▁▁▁▁▁▁▁▁▁",
+            "█████████▁▁▁▁▁▁▁▁▁▁▁",
+            "██████████▁▁▁▁▁▁▁▁▁▁",
+            "███████████▁▁▁▁▁▁▁▁▁",
+            "█████████████▁▁▁▁▁▁▁",
+            "██████████████▁▁▁▁▁▁",
+            "██████████████▁▁▁▁▁▁",
+            "▁██████████████▁▁▁▁▁",
+            "▁██████████████▁▁▁▁▁",
+            "▁██████████████▁▁▁▁▁",
+            "▁▁██████████████▁▁▁▁",
+            "▁▁▁██████████████▁▁▁",
+            "▁▁▁▁█████████████▁▁▁",
+            "▁▁▁▁██████████████▁▁",
+            "▁▁▁▁██████████████▁▁",
+            "▁▁▁▁▁██████████████▁",
+            "▁▁▁▁▁██████████████▁",
+            "▁▁▁▁▁██████████████▁",
+            "▁▁▁▁▁▁██████████████",
+            "▁▁▁▁▁▁██████████████",
+            "▁▁▁▁▁▁▁█████████████",
+            "▁▁▁▁▁▁▁█████████████",
+            "▁▁▁▁▁▁▁▁████████████",
+            "▁▁▁▁▁▁▁▁████████████",
+            "▁▁▁▁▁▁▁▁▁███████████",
+            "▁▁▁▁▁▁▁▁▁███████████",
+            "▁▁▁▁▁▁▁▁▁▁██████████",
+            "▁▁▁▁▁▁▁▁▁▁██████████",
+            "▁▁▁▁▁▁▁▁▁▁▁▁████████",
+            "▁▁▁▁▁▁▁▁▁▁▁▁▁███████",
+            "▁▁▁▁▁▁▁▁▁▁▁▁▁▁██████",
+            "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁█████",
+            "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁█████",
+            "█▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁████",
+            "██▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁███",
+            "██▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁███",
+            "███▁▁▁▁▁▁▁▁▁▁▁▁▁▁███",
+            "████▁▁▁▁▁▁▁▁▁▁▁▁▁▁██",
+            "█████▁▁▁▁▁▁▁▁▁▁▁▁▁▁█",
+            "█████▁▁▁▁▁▁▁▁▁▁▁▁▁▁█",
+            "██████▁▁▁▁▁▁▁▁▁▁▁▁▁█",
+            "████████▁▁▁▁▁▁▁▁▁▁▁▁",
+            "█████████▁▁▁▁▁▁▁▁▁▁▁",
+            "█████████▁▁▁▁▁▁▁▁▁▁▁",
+            "█████████▁▁▁▁▁▁▁▁▁▁▁",
+            "█████████▁▁▁▁▁▁▁▁▁▁▁",
+            "███████████▁▁▁▁▁▁▁▁▁",
+            "████████████▁▁▁▁▁▁▁▁",
+            "████████████▁▁▁▁▁▁▁▁",
+            "██████████████▁▁▁▁▁▁",
+            "██████████████▁▁▁▁▁▁",
+            "▁██████████████▁▁▁▁▁",
+            "▁██████████████▁▁▁▁▁",
+            "▁▁▁█████████████▁▁▁▁",
+            "▁▁▁▁▁████████████▁▁▁",
+            "▁▁▁▁▁████████████▁▁▁",
+            "▁▁▁▁▁▁███████████▁▁▁",
+            "▁▁▁▁▁▁▁▁█████████▁▁▁",
+            "▁▁▁▁▁▁▁▁█████████▁▁▁",
+            "▁▁▁▁▁▁▁▁▁█████████▁▁",
+            "▁▁▁▁▁▁▁▁▁█████████▁▁",
+            "▁▁▁▁▁▁▁▁▁▁█████████▁",
+            "▁▁▁▁▁▁▁▁▁▁▁████████▁",
+            "▁▁▁▁▁▁▁▁▁▁▁████████▁",
+            "▁▁▁▁▁▁▁▁▁▁▁▁███████▁",
+            "▁▁▁▁▁▁▁▁▁▁▁▁███████▁",
+            "▁▁▁▁▁▁▁▁▁▁▁▁▁███████",
+            "▁▁▁▁▁▁▁▁▁▁▁▁▁███████",
+            "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁█████",
+            "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁████",
+            "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁████",
+            "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁████",
+            "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁███",
+            "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁███",
+            "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁██",
+            "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁██",
+            "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁██",
+            "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁█",
+            "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁█",
+            "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁█",
+            "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁",
+            "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁",
+            "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁",
+            "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁"
+        ],
+        17
+    ),
+    ("earth", ["🌍 ", "🌎 ", "🌏 "], 180),
+    (
+        "moon",
+        ["🌑 ", "🌒 ", "🌓 ", "🌔 ", "🌕 ", "🌖 ", "🌗 ", "🌘 "],
+        80
+    ),
+    ("runner", ["🚶 ", "🏃 "], 140),
+    (
+        "pong",
+        [
+            "▐⠂       ▌",
+            "▐⠈       ▌",
+            "▐ ⠂      ▌",
+            "▐ ⠠      ▌",
+            "▐  ⡀     ▌",
+            "▐  ⠠     ▌",
+            "▐   ⠂    ▌",
+            "▐   ⠈    ▌",
+            "▐    ⠂   ▌",
+            "▐    ⠠   ▌",
+            "▐     ⡀  ▌",
+            "▐     ⠠  ▌",
+            "▐      ⠂ ▌",
+            "▐      ⠈ ▌",
+            "▐       ⠂▌",
+            "▐       ⠠▌",
+            "▐       ⡀▌",
+            "▐      ⠠ ▌",
+            "▐      ⠂ ▌",
+            "▐     ⠈  ▌",
+            "▐     ⠂  ▌",
+            "▐    ⠠   ▌",
+            "▐    ⡀   ▌",
+            "▐   ⠠    ▌",
+            "▐   ⠂    ▌",
+            "▐  ⠈     ▌",
+            "▐  ⠂     ▌",
+            "▐ ⠠      ▌",
+            "▐ ⡀      ▌",
+            "▐⠠       ▌"
+        ],
+        80
+    ),
+    (
+        "shark",
+        [
+            "▐|\\____________▌",
+            "▐_|\\___________▌",
+            "▐__|\\__________▌",
+            "▐___|\\_________▌",
+            "▐____|\\________▌",
+            "▐_____|\\_______▌",
+            "▐______|\\______▌",
+            "▐_______|\\_____▌",
+            "▐________|\\____▌",
+            "▐_________|\\___▌",
+            "▐__________|\\__▌",
+            "▐___________|\\_▌",
+            "▐____________|\\▌",
+            "▐____________/|▌",
+            "▐___________/|_▌",
+            "▐__________/|__▌",
+            "▐_________/|___▌",
+            "▐________/|____▌",
+            "▐_______/|_____▌",
+            "▐______/|______▌",
+            "▐_____/|_______▌",
+            "▐____/|________▌",
+            "▐___/|_________▌",
+            "▐__/|__________▌",
+            "▐_/|___________▌",
+            "▐/|____________▌"
+        ],
+        120
+    ),
+    ("dqpb", ["d", "q", "p", "b"], 100),
+    (
+        "weather",
+        [
+            "☀️ ", "☀️ ", "☀️ ", "🌤 ", "⛅️ ", "🌥 ", "☁️ ", "🌧 ", "🌨 ", "🌧 ", "🌨 ", "🌧 ", "🌨 ", "⛈ ", "🌨 ",
+            "🌧 ", "🌨 ", "☁️ ", "🌥 ", "⛅️ ", "🌤 ", "☀️ ", "☀️ "
+        ],
+        100
+    ),
+    ("christmas", ["🌲", "🎄"], 400),
+    (
+        "grenade",
+        [
+            "،  ", "′  ", " ´ ", " ‾ ", "  ⸌", "  ⸊", "  |", "  ⁎", "  ⁕", " ෴ ", "  ⁓", "   ", "   ",
+            "   "
+        ],
+        80
+    ),
+    ("point", ["∙∙∙", "●∙∙", "∙●∙", "∙∙●", "∙∙∙"], 125),
+    ("layer", ["-", "=", "≡"], 150),
+    (
+        "beta_wave",
+        [
+            "ρββββββ",
+            "βρβββββ",
+            "ββρββββ",
+            "βββρβββ",
+            "ββββρββ",
+            "βββββρβ",
+            "ββββββρ"
+        ],
+        80
+    ),
+    (
+        "finger_dance",
+        ["🤘 ", "🤟 ", "🖖 ", "✋ ", "🤚 ", "👆 "],
+        160
+    ),
+    (
+        "fist_bump",
+        [
+            "🤜　　　　🤛 ",
+            "🤜　　　　🤛 ",
+            "🤜　　　　🤛 ",
+            "　🤜　　🤛　 ",
+            "　　🤜🤛　　 ",
+            "　🤜✨🤛　　 ",
+            "🤜　✨　🤛　 "
+        ],
+        80
+    ),
+    (
+        "soccer_header",
+        [
+            " 🧑⚽️       🧑 ",
+            "🧑  ⚽️      🧑 ",
+            "🧑   ⚽️     🧑 ",
+            "🧑    ⚽️    🧑 ",
+            "🧑     ⚽️   🧑 ",
+            "🧑      ⚽️  🧑 ",
+            "🧑       ⚽️🧑  ",
+            "🧑      ⚽️  🧑 ",
+            "🧑     ⚽️   🧑 ",
+            "🧑    ⚽️    🧑 ",
+            "🧑   ⚽️     🧑 ",
+            "🧑  ⚽️      🧑 "
+        ],
+        80
+    ),
+    (
+        "mindblown",
+        [
+            "😐 ", "😐 ", "😮 ", "😮 ", "😦 ", "😦 ", "😧 ", "😧 ", "🤯 ", "💥 ", "✨ ", "　 ", "　 ",
+            "　 "
+        ],
+        160
+    ),
+    ("speaker", ["🔈 ", "🔉 ", "🔊 ", "🔉 "], 160),
+    ("orange_pulse", ["🔸 ", "🔶 ", "🟠 ", "🟠 ", "🔶 "], 100),
+    ("blue_pulse", ["🔹 ", "🔷 ", "🔵 ", "🔵 ", "🔷 "], 100),
+    (
+        "orange_blue_pulse",
+        ["🔸 ", "🔶 ", "🟠 ", "🟠 ", "🔶 ", "🔹 ", "🔷 ", "🔵 ", "🔵 ", "🔷 "],
+        100
+    ),
+    (
+        "time_travel",
+        [
+            "🕛 ", "🕚 ", "🕙 ", "🕘 ", "🕗 ", "🕖 ", "🕕 ", "🕔 ", "🕓 ", "🕒 ", "🕑 ", "🕐 "
+        ],
+        100
+    ),
+    (
+        "aesthetic",
+        [
+            "▰▱▱▱▱▱▱",
+            "▰▰▱▱▱▱▱",
+            "▰▰▰▱▱▱▱",
+            "▰▰▰▰▱▱▱",
+            "▰▰▰▰▰▱▱",
+            "▰▰▰▰▰▰▱",
+            "▰▰▰▰▰▰▰",
+            "▰▱▱▱▱▱▱"
+        ],
+        80
+    ),
+    (
+        "binary",
+        ["010010", "001100", "100101", "111010", "111101", "010111"],
+        50
+    ),
+    (
+        "cute",
+        [
+            "( ´･ω･)",
+            "(　´･ω)",
+            "( 　´･)",
+            "( 　 ´)",
+            "(     )",
+            "(`　  )",
+            "(･`   )",
+            "(ω･`　)",
+            "(･ω･` )",
+            "(´･ω･`)"
+        ],
+        100
+    ),
+}
 
-spinner_frames!("pipe", ["┤", "┘", "┴", "└", "├", "┌", "┬", "┐"], 100);
-
-spinner_frames!("simple_dots", [".  ", ".. ", "...", "   "], 400);
-
-spinner_frames!(
-    "simple_dots_scrolling",
-    [".  ", ".. ", "...", " ..", "  .", "   "],
-    200
-);
-
-spinner_frames!("star", ["✶", "✸", "✹", "✺", "✹", "✷"], 70);
-
-spinner_frames!("star2", ["+", "x", "*"], 80);
-
-spinner_frames!(
-    "flip",
-    ["_", "_", "_", "-", "`", "`", "'", "´", "-", "_", "_", "_"],
-    70
-);
-
-spinner_frames!("hamburger", ["☱", "☲", "☴"], 100);
-
-spinner_frames!(
-    "grow_vertical",
-    ["▁", "▃", "▄", "▅", "▆", "▇", "▆", "▅", "▄", "▃"],
-    120
-);
-
-spinner_frames!(
-    "grow_horizontal",
-    ["▏", "▎", "▍", "▌", "▋", "▊", "▉", "▊", "▋", "▌", "▍", "▎"],
-    120
-);
-
-spinner_frames!("balloon", [" ", ".", "o", "O", "@", "*", " "], 140);
-
-spinner_frames!("balloon2", [".", "o", "O", "°", "O", "o", "."], 120);
-
-spinner_frames!("noise", ["▓", "▒", "░"], 100);
-
-spinner_frames!("bounce", ["⠁", "⠂", "⠄", "⠂"], 120);
-
-spinner_frames!("box_bounce", ["▖", "▘", "▝", "▗"], 120);
-
-spinner_frames!("box_bounce2", ["▌", "▀", "▐", "▄"], 100);
-
-spinner_frames!("triangle", ["◢", "◣", "◤", "◥"], 50);
-
-spinner_frames!("arc", ["◜", "◠", "◝", "◞", "◡", "◟"], 100);
-
-spinner_frames!("circle", ["◡", "⊙", "◠"], 120);
-
-spinner_frames!("square_corners", ["◰", "◳", "◲", "◱"], 180);
-
-spinner_frames!("circle_quarters", ["◴", "◷", "◶", "◵"], 120);
-
-spinner_frames!("circle_halves", ["◐", "◓", "◑", "◒"], 50);
-
-spinner_frames!("squish", ["╫", "╪"], 100);
-
-spinner_frames!("toggle", ["⊶", "⊷"], 250);
-
-spinner_frames!("toggle2", ["▫", "▪"], 80);
-
-spinner_frames!("toggle3", ["□", "■"], 120);
-
-spinner_frames!("toggle4", ["■", "□", "▪", "▫"], 100);
-
-spinner_frames!("toggle5", ["▮", "▯"], 100);
-
-spinner_frames!("toggle6", ["ဝ", "၀"], 300);
-
-spinner_frames!("toggle7", ["⦾", "⦿"], 80);
-
-spinner_frames!("toggle8", ["◍", "◌"], 100);
-
-spinner_frames!("toggle9", ["◉", "◎"], 100);
-
-spinner_frames!("toggle10", ["㊂", "㊀", "㊁"], 100);
-
-spinner_frames!("toggle11", ["⧇", "⧆"], 50);
-
-spinner_frames!("toggle12", ["☗", "☖"], 120);
-
-spinner_frames!("toggle13", ["=", "*", "-"], 80);
-
-spinner_frames!("arrow", ["←", "↖", "↑", "↗", "→", "↘", "↓", "↙"], 100);
-
-spinner_frames!(
-    "arrow2",
-    ["⬆️ ", "↗️ ", "➡️ ", "↘️ ", "⬇️ ", "↙️ ", "⬅️ ", "↖️ "],
-    80
-);
-
-spinner_frames!(
-    "arrow3",
-    ["▹▹▹▹▹", "▸▹▹▹▹", "▹▸▹▹▹", "▹▹▸▹▹", "▹▹▹▸▹", "▹▹▹▹▸"],
-    120
-);
-
-spinner_frames!(
-    "bouncing_bar",
-    [
-        "[    ]", "[=   ]", "[==  ]", "[=== ]", "[ ===]", "[  ==]", "[   =]", "[    ]", "[   =]",
-        "[  ==]", "[ ===]", "[====]", "[=== ]", "[==  ]", "[=   ]"
-    ],
-    80
-);
-
-spinner_frames!(
-    "bouncing_ball",
-    [
-        "( ●    )",
-        "(  ●   )",
-        "(   ●  )",
-        "(    ● )",
-        "(     ●)",
-        "(    ● )",
-        "(   ●  )",
-        "(  ●   )",
-        "( ●    )",
-        "(●     )"
-    ],
-    80
-);
-
-spinner_frames!("smiley", ["😄 ", "😝 "], 200);
-
-spinner_frames!("monkey", ["🙈 ", "🙈 ", "🙉 ", "🙊 "], 300);
-
-spinner_frames!("hearts", ["💛 ", "💙 ", "💜 ", "💚 ", "❤️ "], 100);
-
-spinner_frames!(
-    "clock",
-    ["🕛 ", "🕐 ", "🕑 ", "🕒 ", "🕓 ", "🕔 ", "🕕 "],
-    100
-);
-
-spinner_frames!(
-    "material",
-    [
-        "█▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁",
-        "██▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁",
-        "███▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁",
-        "████▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁",
-        "██████▁▁▁▁▁▁▁▁▁▁▁▁▁▁",
-        "██████▁▁▁▁▁▁▁▁▁▁▁▁▁▁",
-        "███████▁▁▁▁▁▁▁▁▁▁▁▁▁",
-        "████████▁▁▁▁▁▁▁▁▁▁▁▁",
-        "█████████▁▁▁▁▁▁▁▁▁▁▁",
-        "█████████▁▁▁▁▁▁▁▁▁▁▁",
-        "██████████▁▁▁▁▁▁▁▁▁▁",
-        "███████████▁▁▁▁▁▁▁▁▁",
-        "█████████████▁▁▁▁▁▁▁",
-        "██████████████▁▁▁▁▁▁",
-        "██████████████▁▁▁▁▁▁",
-        "▁██████████████▁▁▁▁▁",
-        "▁██████████████▁▁▁▁▁",
-        "▁██████████████▁▁▁▁▁",
-        "▁▁██████████████▁▁▁▁",
-        "▁▁▁██████████████▁▁▁",
-        "▁▁▁▁█████████████▁▁▁",
-        "▁▁▁▁██████████████▁▁",
-        "▁▁▁▁██████████████▁▁",
-        "▁▁▁▁▁██████████████▁",
-        "▁▁▁▁▁██████████████▁",
-        "▁▁▁▁▁██████████████▁",
-        "▁▁▁▁▁▁██████████████",
-        "▁▁▁▁▁▁██████████████",
-        "▁▁▁▁▁▁▁█████████████",
-        "▁▁▁▁▁▁▁█████████████",
-        "▁▁▁▁▁▁▁▁████████████",
-        "▁▁▁▁▁▁▁▁████████████",
-        "▁▁▁▁▁▁▁▁▁███████████",
-        "▁▁▁▁▁▁▁▁▁███████████",
-        "▁▁▁▁▁▁▁▁▁▁██████████",
-        "▁▁▁▁▁▁▁▁▁▁██████████",
-        "▁▁▁▁▁▁▁▁▁▁▁▁████████",
-        "▁▁▁▁▁▁▁▁▁▁▁▁▁███████",
-        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁██████",
-        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁█████",
-        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁█████",
-        "█▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁████",
-        "██▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁███",
-        "██▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁███",
-        "███▁▁▁▁▁▁▁▁▁▁▁▁▁▁███",
-        "████▁▁▁▁▁▁▁▁▁▁▁▁▁▁██",
-        "█████▁▁▁▁▁▁▁▁▁▁▁▁▁▁█",
-        "█████▁▁▁▁▁▁▁▁▁▁▁▁▁▁█",
-        "██████▁▁▁▁▁▁▁▁▁▁▁▁▁█",
-        "████████▁▁▁▁▁▁▁▁▁▁▁▁",
-        "█████████▁▁▁▁▁▁▁▁▁▁▁",
-        "█████████▁▁▁▁▁▁▁▁▁▁▁",
-        "█████████▁▁▁▁▁▁▁▁▁▁▁",
-        "█████████▁▁▁▁▁▁▁▁▁▁▁",
-        "███████████▁▁▁▁▁▁▁▁▁",
-        "████████████▁▁▁▁▁▁▁▁",
-        "████████████▁▁▁▁▁▁▁▁",
-        "██████████████▁▁▁▁▁▁",
-        "██████████████▁▁▁▁▁▁",
-        "▁██████████████▁▁▁▁▁",
-        "▁██████████████▁▁▁▁▁",
-        "▁▁▁█████████████▁▁▁▁",
-        "▁▁▁▁▁████████████▁▁▁",
-        "▁▁▁▁▁████████████▁▁▁",
-        "▁▁▁▁▁▁███████████▁▁▁",
-        "▁▁▁▁▁▁▁▁█████████▁▁▁",
-        "▁▁▁▁▁▁▁▁█████████▁▁▁",
-        "▁▁▁▁▁▁▁▁▁█████████▁▁",
-        "▁▁▁▁▁▁▁▁▁█████████▁▁",
-        "▁▁▁▁▁▁▁▁▁▁█████████▁",
-        "▁▁▁▁▁▁▁▁▁▁▁████████▁",
-        "▁▁▁▁▁▁▁▁▁▁▁████████▁",
-        "▁▁▁▁▁▁▁▁▁▁▁▁███████▁",
-        "▁▁▁▁▁▁▁▁▁▁▁▁███████▁",
-        "▁▁▁▁▁▁▁▁▁▁▁▁▁███████",
-        "▁▁▁▁▁▁▁▁▁▁▁▁▁███████",
-        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁█████",
-        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁████",
-        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁████",
-        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁████",
-        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁███",
-        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁███",
-        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁██",
-        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁██",
-        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁██",
-        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁█",
-        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁█",
-        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁█",
-        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁",
-        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁",
-        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁",
-        "▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁"
-    ],
-    17
-);
-
-spinner_frames!("earth", ["🌍 ", "🌎 ", "🌏 "], 180);
-
-spinner_frames!(
-    "moon",
-    ["🌑 ", "🌒 ", "🌓 ", "🌔 ", "🌕 ", "🌖 ", "🌗 ", "🌘 "],
-    80
-);
-
-spinner_frames!("runner", ["🚶 ", "🏃 "], 140);
-
-spinner_frames!(
-    "pong",
-    [
-        "▐⠂       ▌",
-        "▐⠈       ▌",
-        "▐ ⠂      ▌",
-        "▐ ⠠      ▌",
-        "▐  ⡀     ▌",
-        "▐  ⠠     ▌",
-        "▐   ⠂    ▌",
-        "▐   ⠈    ▌",
-        "▐    ⠂   ▌",
-        "▐    ⠠   ▌",
-        "▐     ⡀  ▌",
-        "▐     ⠠  ▌",
-        "▐      ⠂ ▌",
-        "▐      ⠈ ▌",
-        "▐       ⠂▌",
-        "▐       ⠠▌",
-        "▐       ⡀▌",
-        "▐      ⠠ ▌",
-        "▐      ⠂ ▌",
-        "▐     ⠈  ▌",
-        "▐     ⠂  ▌",
-        "▐    ⠠   ▌",
-        "▐    ⡀   ▌",
-        "▐   ⠠    ▌",
-        "▐   ⠂    ▌",
-        "▐  ⠈     ▌",
-        "▐  ⠂     ▌",
-        "▐ ⠠      ▌",
-        "▐ ⡀      ▌",
-        "▐⠠       ▌"
-    ],
-    80
-);
-
-spinner_frames!(
-    "shark",
-    [
-        "▐|\\____________▌",
-        "▐_|\\___________▌",
-        "▐__|\\__________▌",
-        "▐___|\\_________▌",
-        "▐____|\\________▌",
-        "▐_____|\\_______▌",
-        "▐______|\\______▌",
-        "▐_______|\\_____▌",
-        "▐________|\\____▌",
-        "▐_________|\\___▌",
-        "▐__________|\\__▌",
-        "▐___________|\\_▌",
-        "▐____________|\\▌",
-        "▐____________/|▌",
-        "▐___________/|_▌",
-        "▐__________/|__▌",
-        "▐_________/|___▌",
-        "▐________/|____▌",
-        "▐_______/|_____▌",
-        "▐______/|______▌",
-        "▐_____/|_______▌",
-        "▐____/|________▌",
-        "▐___/|_________▌",
-        "▐__/|__________▌",
-        "▐_/|___________▌",
-        "▐/|____________▌"
-    ],
-    120
-);
-
-spinner_frames!("dqpb", ["d", "q", "p", "b"], 100);
-
-spinner_frames!(
-    "weather",
-    [
-        "☀️ ", "☀️ ", "☀️ ", "🌤 ", "⛅️ ", "🌥 ", "☁️ ", "🌧 ", "🌨 ", "🌧 ", "🌨 ", "🌧 ", "🌨 ", "⛈ ", "🌨 ",
-        "🌧 ", "🌨 ", "☁️ ", "🌥 ", "⛅️ ", "🌤 ", "☀️ ", "☀️ "
-    ],
-    100
-);
-
-spinner_frames!("christmas", ["🌲", "🎄"], 400);
-
-spinner_frames!(
-    "grenade",
-    [
-        "،  ", "′  ", " ´ ", " ‾ ", "  ⸌", "  ⸊", "  |", "  ⁎", "  ⁕", " ෴ ", "  ⁓", "   ", "   ",
-        "   "
-    ],
-    80
-);
-
-spinner_frames!("point", ["∙∙∙", "●∙∙", "∙●∙", "∙∙●", "∙∙∙"], 125);
-
-spinner_frames!("layer", ["-", "=", "≡"], 150);
-
-spinner_frames!(
-    "beta_wave",
-    [
-        "ρββββββ",
-        "βρβββββ",
-        "ββρββββ",
-        "βββρβββ",
-        "ββββρββ",
-        "βββββρβ",
-        "ββββββρ"
-    ],
-    80
-);
-
-spinner_frames!(
-    "finger_dance",
-    ["🤘 ", "🤟 ", "🖖 ", "✋ ", "🤚 ", "👆 "],
-    160
-);
-
-spinner_frames!(
-    "fist_bump",
-    [
-        "🤜　　　　🤛 ",
-        "🤜　　　　🤛 ",
-        "🤜　　　　🤛 ",
-        "　🤜　　🤛　 ",
-        "　　🤜🤛　　 ",
-        "　🤜✨🤛　　 ",
-        "🤜　✨　🤛　 "
-    ],
-    80
-);
-
-spinner_frames!(
-    "soccer_header",
-    [
-        " 🧑⚽️       🧑 ",
-        "🧑  ⚽️      🧑 ",
-        "🧑   ⚽️     🧑 ",
-        "🧑    ⚽️    🧑 ",
-        "🧑     ⚽️   🧑 ",
-        "🧑      ⚽️  🧑 ",
-        "🧑       ⚽️🧑  ",
-        "🧑      ⚽️  🧑 ",
-        "🧑     ⚽️   🧑 ",
-        "🧑    ⚽️    🧑 ",
-        "🧑   ⚽️     🧑 ",
-        "🧑  ⚽️      🧑 "
-    ],
-    80
-);
-
-spinner_frames!(
-    "mindblown",
-    [
-        "😐 ", "😐 ", "😮 ", "😮 ", "😦 ", "😦 ", "😧 ", "😧 ", "🤯 ", "💥 ", "✨ ", "　 ", "　 ",
-        "　 "
-    ],
-    160
-);
-
-spinner_frames!("speaker", ["🔈 ", "🔉 ", "🔊 ", "🔉 "], 160);
-
-spinner_frames!("orange_pulse", ["🔸 ", "🔶 ", "🟠 ", "🟠 ", "🔶 "], 100);
- 
-spinner_frames!("blue_pulse", ["🔹 ", "🔷 ", "🔵 ", "🔵 ", "🔷 "], 100);
-
-spinner_frames!(
-    "orange_blue_pulse",
-    ["🔸 ", "🔶 ", "🟠 ", "🟠 ", "🔶 ", "🔹 ", "🔷 ", "🔵 ", "🔵 ", "🔷 "],
-    100
-);
-
-spinner_frames!(
-    "time_travel",
-    [
-        "🕛 ", "🕚 ", "🕙 ", "🕘 ", "🕗 ", "🕖 ", "🕕 ", "🕔 ", "🕓 ", "🕒 ", "🕑 ", "🕐 "
-    ],
-    100
-);
-
-spinner_frames!(
-    "aesthetic",
-    [
-        "▰▱▱▱▱▱▱",
-        "▰▰▱▱▱▱▱",
-        "▰▰▰▱▱▱▱",
-        "▰▰▰▰▱▱▱",
-        "▰▰▰▰▰▱▱",
-        "▰▰▰▰▰▰▱",
-        "▰▰▰▰▰▰▰",
-        "▰▱▱▱▱▱▱"
-    ],
-    80
-);
-
-spinner_frames!(
-    "binary",
-    ["010010", "001100", "100101", "111010", "111101", "010111"],
-    50
-);
-
-spinner_frames!(
-    "cute",
-    [
-        "( ´･ω･)",
-        "(　´･ω)",
-        "( 　´･)",
-        "( 　 ´)",
-        "(     )",
-        "(`　  )",
-        "(･`   )",
-        "(ω･`　)",
-        "(･ω･` )",
-        "(´･ω･`)"
-    ],
-    100
-);
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The spinner features enabled by the `all` feature in `Cargo.toml`.
+    #[cfg(feature = "all")]
+    fn all_feature() -> Vec<&'static str> {
+        let manifest = include_str!("../Cargo.toml");
+        let list = manifest
+            .split_once("\nall = [")
+            .and_then(|(_, rest)| rest.split_once(']'))
+            .expect("`all` feature in Cargo.toml")
+            .0;
+        list.split(',')
+            .map(|name| name.trim().trim_matches('"'))
+            .filter(|name| !name.is_empty())
+            .collect()
+    }
+
+    #[test]
+    #[cfg(feature = "all")]
+    fn every_feature_has_a_spinner() {
+        let mut features = all_feature();
+        let mut names: Vec<_> = all().map(|(name, _)| name).collect();
+        features.sort_unstable();
+        names.sort_unstable();
+        assert_eq!(names, features);
+        for name in features {
+            assert!(by_name(name).is_some(), "{name}");
+        }
+    }
+
+    #[test]
+    fn every_spinner_has_frames() {
+        for (name, frames) in all() {
+            assert!(!frames.frames.is_empty(), "{name}");
+        }
+    }
+}
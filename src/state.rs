@@ -0,0 +1,519 @@
+use std::borrow::Cow;
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+use std::io::Write;
+use std::time::{Duration, Instant};
+
+use crate::spinners::{Playback, SpinnerFrames};
+use crate::template::Template;
+use crate::utils::{delete_last_line, display_width, format_elapsed};
+use crate::{Color, ColorPolicy, DropBehavior, Output, Routing, Style, Symbols};
+
+/// Number of characters in the progress bar.
+const BAR_WIDTH: usize = 10;
+
+/// Everything needed to draw a spinner, shared between its handle and the render thread.
+///
+/// The handle changes the fields directly, and the render thread picks them up on its next tick,
+/// so updates never restart the animation.
+#[allow(clippy::struct_excessive_bools)]
+pub(crate) struct SpinnerState {
+    pub frames: SpinnerFrames,
+    pub msg: Cow<'static, str>,
+    pub color: Option<Color>,
+    /// Index of the frame currently shown.
+    pub frame: usize,
+    /// Number of frames shown so far, which drives animated colors.
+    tick: u64,
+    /// Whether a ping-pong animation is on its way back to the first frame.
+    backwards: bool,
+    /// State of the random number generator for random playback.
+    rng: u64,
+    /// Display width of the line written by the last call to [`SpinnerState::draw`].
+    pub last_width: usize,
+    /// Whether the spinner is drawn on a terminal. Otherwise only messages are printed, one per line.
+    pub animated: bool,
+    /// The last message printed when not animated.
+    printed: Option<Cow<'static, str>>,
+    /// When the spinner was created.
+    pub started: Instant,
+    /// Whether the time since `started` is shown next to the message.
+    pub show_elapsed: bool,
+    /// Amount of work to do. When set, a progress bar is shown next to the message.
+    pub total: Option<u64>,
+    /// Amount of work done so far.
+    pub position: u64,
+    /// Layout of the line. When not set, the message follows the frame.
+    pub template: Option<Template>,
+    pub frame_style: Style,
+    pub msg_style: Style,
+    /// Style of the symbols printed by the `success` type methods, bold by default.
+    pub symbol_style: Style,
+    pub symbols: Symbols,
+    /// Whether colors and styles are printed, as decided by the color policy.
+    pub colors: bool,
+    pub color_policy: ColorPolicy,
+    pub routing: Routing,
+}
+
+impl SpinnerState {
+    pub fn new(
+        frames: SpinnerFrames,
+        msg: Cow<'static, str>,
+        color: Option<Color>,
+        stream: &Output,
+    ) -> Self {
+        let frames = drawable(frames);
+        Self {
+            frame: first_frame(&frames),
+            frames,
+            msg,
+            color,
+            tick: 0,
+            backwards: false,
+            // Only used to pick frames, so a random seed from the standard library is enough.
+            rng: RandomState::new().hash_one(0u8) | 1,
+            last_width: 0,
+            animated: stream.is_terminal(),
+            printed: None,
+            started: Instant::now(),
+            show_elapsed: false,
+            total: None,
+            position: 0,
+            template: None,
+            frame_style: Style::new(),
+            msg_style: Style::new(),
+            symbol_style: Style::new().bold(),
+            symbols: Symbols::default_symbols(),
+            colors: ColorPolicy::default_policy().enabled(stream),
+            color_policy: ColorPolicy::default_policy(),
+            routing: Routing::default(),
+        }
+    }
+
+    /// Replace the frames, keeping the position of the animation.
+    pub fn set_frames(&mut self, frames: SpinnerFrames) {
+        let frames = drawable(frames);
+        self.frame %= frames.frames.len();
+        self.frames = frames;
+    }
+
+    /// Replace the frames, message and color, as done by the `update` methods.
+    pub fn update(&mut self, frames: SpinnerFrames, msg: Cow<'static, str>, color: Option<Color>) {
+        self.set_frames(frames);
+        self.msg = msg;
+        self.color = color;
+    }
+
+    /// Decide again whether colors are printed to `stream`.
+    pub fn set_color_policy(&mut self, policy: ColorPolicy, stream: &Output) {
+        self.colors = policy.enabled(stream);
+        self.color_policy = policy;
+    }
+
+    /// Move on to the next frame, in the order set by the playback mode.
+    pub fn advance(&mut self) {
+        self.tick = self.tick.wrapping_add(1);
+        let last = self.frames.frames.len() - 1;
+        self.frame = match self.frames.playback {
+            Playback::Reverse => self.frame.checked_sub(1).unwrap_or(last),
+            Playback::Once => (self.frame + 1).min(last),
+            Playback::PingPong if last > 0 => {
+                if self.frame == last {
+                    self.backwards = true;
+                } else if self.frame == 0 {
+                    self.backwards = false;
+                }
+                if self.backwards {
+                    self.frame - 1
+                } else {
+                    self.frame + 1
+                }
+            }
+            Playback::Random if last > 0 => {
+                // Skip ahead by 1 to `last` frames, so the same frame is never shown twice in a row.
+                (self.frame + 1 + self.random() % last) % (last + 1)
+            }
+            _ => (self.frame + 1) % (last + 1),
+        };
+    }
+
+    /// The next number from a xorshift generator.
+    #[allow(clippy::cast_possible_truncation)]
+    fn random(&mut self) -> usize {
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 7;
+        self.rng ^= self.rng << 17;
+        // Only the low bits are needed.
+        self.rng as usize
+    }
+
+    /// How long the current frame is shown, at least a millisecond so that a zero interval doesn't spin the CPU
+    /// or panic tokio's timer.
+    pub fn interval(&self) -> Duration {
+        Duration::from_millis(u64::from(self.frames.duration(self.frame).max(1)))
+    }
+
+    /// The spinner line for the current frame.
+    pub fn render(&self) -> String {
+        let frame = self.paint(self.frame_style, &self.frames.frames[self.frame], self.color);
+        let msg = self.paint(self.msg_style, &self.msg, None);
+        match &self.template {
+            Some(template) => template.render(|key| self.value(key, &frame, &msg)),
+            None => format!(
+                "{frame} {msg}{}{}",
+                self.progress_suffix(),
+                self.elapsed_suffix()
+            ),
+        }
+    }
+
+    /// The line left behind when the spinner stops, with the final elapsed time if it is shown.
+    pub fn finish_line(&self, msg: &str) -> String {
+        format!("{}{}", self.paint(self.msg_style, msg, None), self.elapsed_suffix())
+    }
+
+    /// The line left behind when the spinner stops with a symbol, laid out by the template if there is one.
+    pub fn status_line(&self, symbol: &str, msg: &str) -> String {
+        match &self.template {
+            Some(template) => {
+                let msg = self.paint(self.msg_style, msg, None);
+                template.render(|key| self.value(key, symbol, &msg))
+            }
+            None => format!("{symbol} {}", self.finish_line(msg)),
+        }
+    }
+
+    /// The line left behind by the `success` type methods.
+    pub fn outcome_line(&self, status: Status, msg: &str) -> String {
+        let (color, symbol) = status.symbol(&self.symbols);
+        let symbol = self.paint(self.symbol_style, symbol, Some(color));
+        self.status_line(&symbol, msg)
+    }
+
+    /// The stream the line for `status` goes to, if it isn't the spinner's own.
+    /// Colors are decided again for that stream, so only call this once the spinner is done.
+    pub fn route(&mut self, status: Status) -> Option<Output> {
+        let stream = self.routing.get(status)?.clone();
+        self.colors = self.color_policy.enabled(&stream);
+        Some(stream)
+    }
+
+    /// Print what is left in place of the spinner once it is no longer drawn.
+    pub fn finish(&mut self, finish: Finish<'_>, stream: &Output) {
+        match finish {
+            Finish::Clear => {}
+            Finish::Stop => {
+                // print message, unless it is already on its own line and there is no elapsed time to add
+                if self.animated || self.show_elapsed {
+                    writeln!(stream, "{}", self.finish_line(&self.msg));
+                }
+            }
+            Finish::Message(msg) => writeln!(stream, "{}", self.finish_line(msg)),
+            Finish::Persist(symbol, msg) => writeln!(stream, "{}", self.status_line(symbol, msg)),
+            Finish::Outcome(status, msg) => self.print_outcome(status, msg, stream),
+            Finish::Panic => {
+                let msg = self.msg.clone();
+                self.print_outcome(Status::Fail, &msg, stream);
+            }
+        }
+    }
+
+    /// Print the line left behind by the `success` type methods, to `stream` unless it is routed elsewhere.
+    fn print_outcome(&mut self, status: Status, msg: &str, stream: &Output) {
+        let route = self.route(status);
+        let line = self.outcome_line(status, msg);
+        writeln!(route.as_ref().unwrap_or(stream), "{line}");
+    }
+
+    /// Apply `style` to `text`, with animated colors as they are at the current frame.
+    fn paint(&self, style: Style, text: &str, color: Option<Color>) -> String {
+        let color = color.map(|color| color.at(self.tick));
+        style.at(self.tick).paint(text, color, self.colors)
+    }
+
+    /// Value of a built-in template key.
+    fn value(&self, key: &str, spinner: &str, msg: &str) -> Option<String> {
+        let progress = self.progress();
+        let value = match key {
+            "spinner" => spinner.to_string(),
+            "msg" => msg.to_string(),
+            "elapsed" => format_elapsed(self.started.elapsed()),
+            "pos" => self.position.to_string(),
+            "total" => self.total.map(|total| total.to_string()).unwrap_or_default(),
+            "percent" => progress.map(|(percent, _)| percent.to_string()).unwrap_or_default(),
+            "bar" => progress.map(|(_, bar)| bar).unwrap_or_default(),
+            _ => return None,
+        };
+        Some(value)
+    }
+
+    /// The progress bar and percentage, e.g. ` [#####-----] 52%`, if a total is set.
+    fn progress_suffix(&self) -> String {
+        self.progress()
+            .map(|(percent, bar)| format!(" [{bar}] {percent}%"))
+            .unwrap_or_default()
+    }
+
+    /// The percentage of work done and the progress bar, if a total is set.
+    fn progress(&self) -> Option<(u128, String)> {
+        let total = self.total?;
+        // Nothing to do counts as done.
+        let (done, total) = if total == 0 {
+            (1, 1)
+        } else {
+            (u128::from(self.position.min(total)), u128::from(total))
+        };
+        let filled = usize::try_from(done * BAR_WIDTH as u128 / total).unwrap_or(BAR_WIDTH);
+        let bar = format!("{}{}", "#".repeat(filled), "-".repeat(BAR_WIDTH - filled));
+        Some((done * 100 / total, bar))
+    }
+
+    fn elapsed_suffix(&self) -> String {
+        if self.show_elapsed {
+            format!(" ({})", format_elapsed(self.started.elapsed()))
+        } else {
+            String::new()
+        }
+    }
+
+    /// Replace the previously drawn line with the current one.
+    pub fn draw(&mut self, stream: &Output) {
+        if !self.animated {
+            // There is no line to redraw, so only print messages that weren't printed yet.
+            if self.printed.as_ref() != Some(&self.msg) {
+                writeln!(stream, "{}", self.msg);
+                self.printed = Some(self.msg.clone());
+            }
+            return;
+        }
+        let line = self.render();
+        delete_last_line(self.last_width, stream);
+        self.last_width = display_width(&line);
+        write!(stream, "{line}");
+        stream
+            .get_stream()
+            .flush()
+            .expect("error: failed to flush stream");
+    }
+
+    /// Remove the previously drawn line.
+    pub fn clear(&mut self, stream: &Output) {
+        if !self.animated {
+            return;
+        }
+        delete_last_line(self.last_width, stream);
+        self.last_width = 0;
+    }
+}
+
+/// Frames that can always be drawn. The fields of [`SpinnerFrames`] are public, so they may be empty,
+/// in which case only the message is shown.
+fn drawable(mut frames: SpinnerFrames) -> SpinnerFrames {
+    if frames.frames.is_empty() {
+        frames.frames.push(Cow::Borrowed(""));
+    }
+    frames
+}
+
+/// The frame an animation starts on.
+fn first_frame(frames: &SpinnerFrames) -> usize {
+    match frames.playback {
+        Playback::Reverse => frames.frames.len() - 1,
+        _ => 0,
+    }
+}
+
+/// What is left in place of a spinner when it stops, one for every `stop` type method.
+#[derive(Copy, Clone)]
+pub(crate) enum Finish<'a> {
+    /// Nothing, see [`Spinner::clear`](crate::Spinner::clear).
+    Clear,
+    /// The current message, see [`Spinner::stop`](crate::Spinner::stop).
+    Stop,
+    /// Another message, see [`Spinner::stop_with_message`](crate::Spinner::stop_with_message).
+    Message(&'a str),
+    /// A symbol and a message, see [`Spinner::stop_and_persist`](crate::Spinner::stop_and_persist).
+    Persist(&'a str, &'a str),
+    /// The line for an outcome, see [`Spinner::success`](crate::Spinner::success).
+    Outcome(Status, &'a str),
+    /// A failure with the current message, for spinners dropped while the thread is panicking.
+    Panic,
+}
+
+impl Finish<'_> {
+    /// What a spinner with `behavior` leaves behind when it is dropped while still spinning.
+    pub fn dropped(behavior: DropBehavior) -> Self {
+        match behavior {
+            DropBehavior::Persist => Self::Stop,
+            DropBehavior::FailOnPanic if std::thread::panicking() => Self::Panic,
+            DropBehavior::Clear | DropBehavior::FailOnPanic => Self::Clear,
+        }
+    }
+}
+
+/// The outcomes printed by the `success` type methods.
+#[derive(Copy, Clone)]
+pub(crate) enum Status {
+    Success,
+    Fail,
+    Warn,
+    Info,
+}
+
+impl Status {
+    /// The symbol printed for this outcome, and its color.
+    fn symbol(self, symbols: &Symbols) -> (Color, &str) {
+        match self {
+            Self::Success => (Color::Green, &symbols.success),
+            Self::Fail => (Color::Red, &symbols.fail),
+            Self::Warn => (Color::Yellow, &symbols.warn),
+            Self::Info => (Color::Blue, &symbols.info),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+
+    fn state(frames: SpinnerFrames) -> SpinnerState {
+        let stream = Output::Writer(Arc::new(Mutex::new(Vec::<u8>::new())));
+        SpinnerState::new(frames, "Loading".into(), None, &stream)
+    }
+
+    fn empty() -> SpinnerFrames {
+        SpinnerFrames {
+            frames: Vec::new(),
+            interval: 100,
+            durations: Vec::new(),
+            playback: Playback::Reverse,
+        }
+    }
+
+    #[test]
+    fn empty_frames_show_only_the_message() {
+        let mut state = state(empty());
+        state.advance();
+        assert_eq!(state.render(), " Loading");
+
+        let mut state = self::state(SpinnerFrames::new(["-", "+"], 100));
+        state.advance();
+        state.set_frames(empty());
+        state.advance();
+        assert_eq!(state.render(), " Loading");
+    }
+
+    /// The first `count` frames shown.
+    fn sequence(frames: SpinnerFrames, count: usize) -> Vec<String> {
+        let mut state = state(frames);
+        let mut shown = Vec::new();
+        for _ in 0..count {
+            shown.push(state.frames.frames[state.frame].to_string());
+            state.advance();
+        }
+        shown
+    }
+
+    fn frames(playback: Playback) -> SpinnerFrames {
+        SpinnerFrames::new(["a", "b", "c", "d"], 100).with_playback(playback)
+    }
+
+    #[test]
+    fn loop_playback() {
+        assert_eq!(sequence(frames(Playback::Loop), 6).concat(), "abcdab");
+    }
+
+    #[test]
+    fn ping_pong_playback() {
+        assert_eq!(
+            sequence(frames(Playback::PingPong), 11).concat(),
+            "abcdcbabcdc"
+        );
+        let single = SpinnerFrames::new(["a"], 100).with_playback(Playback::PingPong);
+        assert_eq!(sequence(single, 3).concat(), "aaa");
+    }
+
+    #[test]
+    fn reverse_playback() {
+        assert_eq!(sequence(frames(Playback::Reverse), 6).concat(), "dcbadc");
+    }
+
+    #[test]
+    fn once_playback() {
+        assert_eq!(sequence(frames(Playback::Once), 6).concat(), "abcddd");
+    }
+
+    #[test]
+    fn random_playback() {
+        let shown = sequence(frames(Playback::Random), 1000);
+        assert!(shown.windows(2).all(|pair| pair[0] != pair[1]));
+        for frame in ["a", "b", "c", "d"] {
+            assert!(shown.iter().any(|shown| shown == frame));
+        }
+        let single = SpinnerFrames::new(["a"], 100).with_playback(Playback::Random);
+        assert_eq!(sequence(single, 3).concat(), "aaa");
+    }
+
+    #[test]
+    #[cfg(feature = "dots4")]
+    fn dots4_keeps_its_original_sequence() {
+        let original = [
+            "⠄", "⠆", "⠇", "⠋", "⠙", "⠸", "⠰", "⠠", "⠰", "⠸", "⠙", "⠋", "⠇", "⠆",
+        ];
+        let frames = SpinnerFrames::from(crate::spinners::Dots4);
+        assert_eq!(frames.frames.len(), 8);
+        assert_eq!(sequence(frames, 28), original.repeat(2));
+    }
+
+    #[test]
+    fn interval_is_at_least_a_millisecond() {
+        let state = state(SpinnerFrames::new(["-"], 0));
+        assert_eq!(state.interval(), Duration::from_millis(1));
+    }
+
+    /// The spinner line with `position` out of `total` done, without colors.
+    fn progress_line(total: Option<u64>, position: u64) -> String {
+        let mut state = state(SpinnerFrames::new(["-"], 100));
+        state.colors = false;
+        state.total = total;
+        state.position = position;
+        state.render()
+    }
+
+    #[test]
+    fn progress_shows_the_share_done() {
+        assert_eq!(progress_line(Some(100), 0), "- Loading [----------] 0%");
+        assert_eq!(progress_line(Some(100), 52), "- Loading [#####-----] 52%");
+        assert_eq!(progress_line(Some(3), 2), "- Loading [######----] 66%");
+        assert_eq!(progress_line(Some(100), 100), "- Loading [##########] 100%");
+    }
+
+    #[test]
+    fn progress_without_work_is_done() {
+        assert_eq!(progress_line(Some(0), 0), "- Loading [##########] 100%");
+    }
+
+    #[test]
+    fn progress_past_the_total_stops_at_the_total() {
+        assert_eq!(progress_line(Some(10), 25), "- Loading [##########] 100%");
+        assert_eq!(
+            progress_line(Some(u64::MAX), u64::MAX),
+            "- Loading [##########] 100%"
+        );
+    }
+
+    #[test]
+    fn unsetting_the_total_shows_the_plain_spinner() {
+        let mut state = state(SpinnerFrames::new(["-"], 100));
+        state.colors = false;
+        state.total = Some(100);
+        state.position = 52;
+        assert_eq!(state.render(), "- Loading [#####-----] 52%");
+        // As done by `set_total(None)`.
+        state.total = None;
+        assert_eq!(state.render(), "- Loading");
+    }
+}
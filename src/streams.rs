@@ -1,6 +1,15 @@
-use std::io::{stderr, stdout, Write};
+use std::fmt;
+use std::io::{self, stderr, stdout, IsTerminal, Write};
+use std::sync::{Arc, Mutex};
+use terminal_size::{terminal_size_of, Width};
+
+use crate::state::Status;
+use crate::utils::lock;
+
 /// Simplified type for a stream.
 /// By default, `spinoff` uses `Streams::Stdout`.
+///
+/// To print to any other writer, such as a file or an in-memory buffer, use [`Output`].
 #[derive(Default, Copy, Clone, Debug)]
 pub enum Streams {
     #[default]
@@ -9,6 +18,15 @@ pub enum Streams {
 }
 
 impl Streams {
+    /// Whether the stream is a terminal.
+    /// Spinners only animate on terminals, everything else gets one line per message.
+    #[must_use]
+    pub fn is_terminal(self) -> bool {
+        match self {
+            Self::Stdout => stdout().is_terminal(),
+            Self::Stderr => stderr().is_terminal(),
+        }
+    }
     // Returns the stream to use.
     #[must_use = "Stream must be retrieved"]
     pub fn get_stream(self) -> Box<dyn Write + Send + Sync> {
@@ -24,5 +42,203 @@ impl Streams {
     {
         write!(self.get_stream(), "{fmt}").expect("error: failed to write to stream");
     }
+}
+
+/// Where a spinner is printed: one of the [`Streams`], or any other writer.
+///
+/// Every function that takes an `Output` also takes a [`Streams`].
+#[derive(Clone)]
+pub enum Output {
+    Stream(Streams),
+    /// Any other writer, such as a file, an in-memory buffer or a socket.
+    ///
+    /// Keep a clone of the `Arc` to get at the writer once the spinner is done with it.
+    /// Writers are not treated as terminals, so spinners print plain lines to them.
+    Writer(Arc<Mutex<dyn Write + Send>>),
+    /// A writer connected to a terminal, such as a pty master.
+    /// Spinners animate on it just like they do on `stdout`.
+    Terminal(Arc<Mutex<dyn Write + Send>>),
+}
+
+impl Output {
+    /**
+    Create an output writing to any writer.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let file = std::fs::File::create(std::env::temp_dir().join("spinoff.log")).unwrap();
+    let mut sp = Spinner::new_with_stream(spinners::Dots, "Logging...", None, Output::writer(file));
+    sleep(Duration::from_millis(800));
+    sp.success("Done!");
+    ```
+
+    # Notes
+
+    * To read the output back, e.g. from an in-memory buffer, construct [`Output::Writer`] directly and keep a clone of the `Arc`.
+    */
+    pub fn writer<W>(writer: W) -> Self
+    where
+        W: Write + Send + 'static,
+    {
+        Self::Writer(Arc::new(Mutex::new(writer)))
+    }
+    /**
+    Create an output writing to a writer that is connected to a terminal.
+
+    # Example
 
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let terminal = std::io::stderr();
+    let mut sp = Spinner::new_with_stream(spinners::Dots, "Always animated...", None, Output::terminal(terminal));
+    sleep(Duration::from_millis(800));
+    sp.success("Done!");
+    ```
+
+    */
+    pub fn terminal<W>(writer: W) -> Self
+    where
+        W: Write + Send + 'static,
+    {
+        Self::Terminal(Arc::new(Mutex::new(writer)))
+    }
+    /// Whether the output is a terminal.
+    /// Spinners only animate on terminals, everything else gets one line per message.
+    #[must_use]
+    pub fn is_terminal(&self) -> bool {
+        match self {
+            Self::Stream(stream) => stream.is_terminal(),
+            Self::Writer(_) => false,
+            Self::Terminal(_) => true,
+        }
+    }
+    /// Width of the terminal in columns, if the output is a terminal of known size.
+    pub(crate) fn columns(&self) -> Option<usize> {
+        let (Width(columns), _) = match self {
+            Self::Stream(Streams::Stdout) => terminal_size_of(stdout()),
+            Self::Stream(Streams::Stderr) => terminal_size_of(stderr()),
+            Self::Writer(_) | Self::Terminal(_) => None,
+        }?;
+        Some(usize::from(columns))
+    }
+    // Returns the stream to use.
+    #[must_use = "Stream must be retrieved"]
+    pub fn get_stream(&self) -> Box<dyn Write + Send + Sync> {
+        match self {
+            Self::Stream(stream) => stream.get_stream(),
+            Self::Writer(writer) | Self::Terminal(writer) => {
+                Box::new(SharedWriter(Arc::clone(writer)))
+            }
+        }
+    }
+    // Lets `write!` print to an output, just like to a stream.
+    pub fn write_fmt<T>(&self, fmt: T)
+    where
+        T: std::fmt::Display,
+    {
+        write!(self.get_stream(), "{fmt}").expect("error: failed to write to stream");
+    }
+}
+
+impl Default for Output {
+    fn default() -> Self {
+        Self::Stream(Streams::default())
+    }
+}
+
+impl From<Streams> for Output {
+    fn from(stream: Streams) -> Self {
+        Self::Stream(stream)
+    }
+}
+
+impl fmt::Debug for Output {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Stream(stream) => f.debug_tuple("Stream").field(stream).finish(),
+            Self::Writer(_) => f.write_str("Writer(..)"),
+            Self::Terminal(_) => f.write_str("Terminal(..)"),
+        }
+    }
+}
+
+/**
+Where the lines printed by the `success`, `fail`, `warn` and `info` methods go.
+
+Outcomes without a stream are printed to the spinner's own stream, which is what the default routing does.
+
+# Example
+
+```
+# use spinoff::*;
+# use std::thread::sleep;
+# use std::time::Duration;
+#
+let mut sp = Spinner::new(spinners::Dots, "Checking...", None);
+// Failures still show up when stdout is redirected to a file.
+sp.set_routing(Routing::split());
+sleep(Duration::from_millis(800));
+sp.fail("Check failed!");
+```
+*/
+#[derive(Clone, Debug, Default)]
+pub struct Routing {
+    pub success: Option<Output>,
+    pub fail: Option<Output>,
+    pub warn: Option<Output>,
+    pub info: Option<Output>,
+}
+
+impl Routing {
+    /// Failures go to `stderr`, everything else to `stdout`.
+    #[must_use]
+    pub fn split() -> Self {
+        Self {
+            success: Some(Streams::Stdout.into()),
+            fail: Some(Streams::Stderr.into()),
+            warn: Some(Streams::Stdout.into()),
+            info: Some(Streams::Stdout.into()),
+        }
+    }
+    /// Sends warnings to `stderr` too.
+    #[must_use]
+    pub fn warnings_to_stderr(mut self) -> Self {
+        self.warn = Some(Streams::Stderr.into());
+        self
+    }
+
+    /// The stream the line for `status` goes to, if it isn't the spinner's own.
+    pub(crate) fn get(&self, status: Status) -> Option<&Output> {
+        match status {
+            Status::Success => self.success.as_ref(),
+            Status::Fail => self.fail.as_ref(),
+            Status::Warn => self.warn.as_ref(),
+            Status::Info => self.info.as_ref(),
+        }
+    }
+}
+
+/// Writes to a writer shared with the user, locking it for every call.
+struct SharedWriter(Arc<Mutex<dyn Write + Send>>);
+
+impl Write for SharedWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        lock(&self.0).write(buf)
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        lock(&self.0).write_all(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        lock(&self.0).flush()
+    }
 }
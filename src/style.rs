@@ -0,0 +1,253 @@
+use std::env;
+use std::ffi::OsString;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use crate::{Color, Output};
+
+/// The policy spinners start with, see [`set_default_color_policy`].
+static DEFAULT_POLICY: AtomicU8 = AtomicU8::new(ColorPolicy::Auto as u8);
+
+/**
+Text style for the parts of a spinner line: a color, a background color, and bold, dim, italic or underlined text.
+
+Styles are set separately for the frame, the message and the status symbols printed by the `success` type methods.
+
+# Example
+
+```
+# use spinoff::*;
+# use std::thread::sleep;
+# use std::time::Duration;
+#
+let mut sp = Spinner::new(spinners::Dots, "Loading...", Color::Ansi256(208));
+sp.set_frame_style(Style::new().bold());
+sp.set_message_style(Style::new().italic().color(Color::BrightBlack));
+sp.set_symbol_style(Style::new().on(Color::Black));
+sleep(Duration::from_millis(800));
+sp.success("Done!");
+```
+*/
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct Style {
+    color: Option<Color>,
+    background: Option<Color>,
+    bold: bool,
+    dim: bool,
+    italic: bool,
+    underline: bool,
+}
+
+impl Style {
+    /// A style that leaves the text as it is.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            color: None,
+            background: None,
+            bold: false,
+            dim: false,
+            italic: false,
+            underline: false,
+        }
+    }
+    /// Sets the color of the text. It takes precedence over the color of the spinner or the status.
+    #[must_use]
+    pub const fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+    /// Sets the background color.
+    #[must_use]
+    pub const fn on(mut self, background: Color) -> Self {
+        self.background = Some(background);
+        self
+    }
+    /// Makes the text bold.
+    #[must_use]
+    pub const fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+    /// Makes the text dim.
+    #[must_use]
+    pub const fn dim(mut self) -> Self {
+        self.dim = true;
+        self
+    }
+    /// Makes the text italic.
+    #[must_use]
+    pub const fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+    /// Underlines the text.
+    #[must_use]
+    pub const fn underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+
+    /// The style at frame number `tick`, with its animated colors resolved.
+    pub(crate) fn at(self, tick: u64) -> Self {
+        Self {
+            color: self.color.map(|color| color.at(tick)),
+            background: self.background.map(|color| color.at(tick)),
+            ..self
+        }
+    }
+
+    /// Apply the style to `text`, in `color` unless the style has its own.
+    /// Nothing is applied when `enabled` is false, i.e. colors are turned off.
+    pub(crate) fn paint(self, text: &str, color: Option<Color>, enabled: bool) -> String {
+        let mut codes = Vec::new();
+        if self.bold {
+            codes.push("1".to_string());
+        }
+        if self.dim {
+            codes.push("2".to_string());
+        }
+        if self.italic {
+            codes.push("3".to_string());
+        }
+        if self.underline {
+            codes.push("4".to_string());
+        }
+        if let Some(color) = self.color.or(color) {
+            codes.push(color.sgr(false));
+        }
+        if let Some(background) = self.background {
+            codes.push(background.sgr(true));
+        }
+        if !enabled || codes.is_empty() || text.is_empty() {
+            return text.to_string();
+        }
+        format!("\x1b[{}m{text}\x1b[0m", codes.join(";"))
+    }
+}
+
+/**
+Whether spinners print colors and styles.
+
+Each spinner starts with the process-wide default set by [`set_default_color_policy`], which is [`ColorPolicy::Auto`]
+unless changed. Use e.g. [`Spinner::set_color_policy`](crate::Spinner::set_color_policy) to change it for a single spinner.
+*/
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum ColorPolicy {
+    /// Colors are printed if the stream is a terminal, unless turned off with the `NO_COLOR` or `CLICOLOR=0`
+    /// environment variables. `CLICOLOR_FORCE` turns them on even if the stream is not a terminal.
+    #[default]
+    Auto,
+    /// Colors are always printed.
+    Always,
+    /// Colors are never printed.
+    Never,
+}
+
+impl ColorPolicy {
+    /// Whether colors are printed to `stream` under this policy.
+    pub(crate) fn enabled(self, stream: &Output) -> bool {
+        match self {
+            Self::Always => true,
+            Self::Never => false,
+            Self::Auto => Self::auto_enabled(stream.is_terminal(), |name| env::var_os(name)),
+        }
+    }
+
+    /// Whether colors are printed under [`ColorPolicy::Auto`], with `var` looking up environment variables.
+    fn auto_enabled(terminal: bool, var: impl Fn(&str) -> Option<OsString>) -> bool {
+        // See https://bixense.com/clicolors/ and https://no-color.org/.
+        let set = |name| var(name).is_some_and(|value| !value.is_empty());
+        let is_zero = |name| var(name).is_some_and(|value| value == "0");
+        if set("CLICOLOR_FORCE") && !is_zero("CLICOLOR_FORCE") {
+            return true;
+        }
+        !set("NO_COLOR") && !is_zero("CLICOLOR") && terminal
+    }
+
+    /// The policy set with [`set_default_color_policy`].
+    pub(crate) fn default_policy() -> Self {
+        match DEFAULT_POLICY.load(Ordering::Relaxed) {
+            policy if policy == Self::Always as u8 => Self::Always,
+            policy if policy == Self::Never as u8 => Self::Never,
+            _ => Self::Auto,
+        }
+    }
+}
+
+/**
+Sets the [`ColorPolicy`] of every spinner created from now on. Spinners that already exist keep theirs.
+
+# Example
+
+```
+# use spinoff::*;
+# use std::thread::sleep;
+# use std::time::Duration;
+#
+// E.g. for a `--color=never` flag.
+spinoff::set_default_color_policy(ColorPolicy::Never);
+let mut sp = Spinner::new(spinners::Dots, "Loading...", Color::Blue);
+sleep(Duration::from_millis(800));
+sp.success("No colors here!");
+```
+*/
+pub fn set_default_color_policy(policy: ColorPolicy) {
+    DEFAULT_POLICY.store(policy as u8, Ordering::Relaxed);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Whether colors are printed under [`ColorPolicy::Auto`] with only the given variables set.
+    fn auto(terminal: bool, vars: &[(&str, &str)]) -> bool {
+        ColorPolicy::auto_enabled(terminal, |name| {
+            vars.iter()
+                .find(|(var, _)| *var == name)
+                .map(|(_, value)| OsString::from(value))
+        })
+    }
+
+    #[test]
+    fn auto_follows_the_terminal() {
+        assert!(auto(true, &[]));
+        assert!(!auto(false, &[]));
+    }
+
+    #[test]
+    fn no_color_turns_colors_off() {
+        assert!(!auto(true, &[("NO_COLOR", "1")]));
+        assert!(!auto(true, &[("NO_COLOR", "0")]));
+        // An empty value counts as unset.
+        assert!(auto(true, &[("NO_COLOR", "")]));
+    }
+
+    #[test]
+    fn clicolor_zero_turns_colors_off() {
+        assert!(!auto(true, &[("CLICOLOR", "0")]));
+        assert!(auto(true, &[("CLICOLOR", "1")]));
+        // CLICOLOR only turns colors off, never on.
+        assert!(!auto(false, &[("CLICOLOR", "1")]));
+    }
+
+    #[test]
+    fn clicolor_force_wins() {
+        assert!(auto(false, &[("CLICOLOR_FORCE", "1")]));
+        assert!(auto(true, &[("CLICOLOR_FORCE", "1"), ("NO_COLOR", "1")]));
+        assert!(auto(true, &[("CLICOLOR_FORCE", "1"), ("CLICOLOR", "0")]));
+        // Set to 0 or empty, it is ignored.
+        assert!(!auto(false, &[("CLICOLOR_FORCE", "0")]));
+        assert!(!auto(false, &[("CLICOLOR_FORCE", "")]));
+        assert!(!auto(true, &[("CLICOLOR_FORCE", "0"), ("NO_COLOR", "1")]));
+    }
+
+    #[test]
+    fn always_and_never_ignore_the_environment() {
+        let stream = Output::writer(Vec::new());
+        assert!(ColorPolicy::Always.enabled(&stream));
+        assert!(!ColorPolicy::Never.enabled(&stream));
+    }
+}
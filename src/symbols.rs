@@ -0,0 +1,103 @@
+use std::borrow::Cow;
+use std::env;
+use std::sync::{PoisonError, RwLock};
+
+/// The symbols spinners start with, see [`set_default_symbols`]. `None` picks them based on the locale.
+static DEFAULT_SYMBOLS: RwLock<Option<Symbols>> = RwLock::new(None);
+
+/**
+The symbols printed by the `success`, `fail`, `warn` and `info` methods.
+
+By default, spinners use [`Symbols::UNICODE`], or [`Symbols::ASCII`] if the locale is not UTF-8.
+
+# Example
+
+```
+# use spinoff::*;
+# use std::thread::sleep;
+# use std::time::Duration;
+#
+let mut sp = Spinner::new(spinners::Dots, "Loading...", None);
+sp.set_symbols(Symbols {
+    success: "✔".into(),
+    fail: "✖".into(),
+    ..Symbols::UNICODE
+});
+sleep(Duration::from_millis(800));
+sp.success("Loaded!");
+```
+*/
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Symbols {
+    pub success: Cow<'static, str>,
+    pub fail: Cow<'static, str>,
+    pub warn: Cow<'static, str>,
+    pub info: Cow<'static, str>,
+}
+
+impl Symbols {
+    /// `✓`, `✗`, `⚠` and `ℹ`.
+    pub const UNICODE: Self = Self {
+        success: Cow::Borrowed("✓"),
+        fail: Cow::Borrowed("✗"),
+        warn: Cow::Borrowed("⚠"),
+        info: Cow::Borrowed("ℹ"),
+    };
+    /// `[ok]`, `[x]`, `[!]` and `[i]`, for terminals and fonts without the Unicode symbols.
+    pub const ASCII: Self = Self {
+        success: Cow::Borrowed("[ok]"),
+        fail: Cow::Borrowed("[x]"),
+        warn: Cow::Borrowed("[!]"),
+        info: Cow::Borrowed("[i]"),
+    };
+
+    /// The symbols set with [`set_default_symbols`], or the ones matching the locale.
+    pub(crate) fn default_symbols() -> Self {
+        let symbols = DEFAULT_SYMBOLS
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+        match &*symbols {
+            Some(symbols) => symbols.clone(),
+            None if utf8_locale() => Self::UNICODE,
+            None => Self::ASCII,
+        }
+    }
+}
+
+/**
+Sets the [`Symbols`] of every spinner created from now on. Spinners that already exist keep theirs.
+
+# Example
+
+```
+# use spinoff::*;
+# use std::thread::sleep;
+# use std::time::Duration;
+#
+spinoff::set_default_symbols(Symbols::ASCII);
+let mut sp = Spinner::new(spinners::Line, "Loading...", None);
+sleep(Duration::from_millis(800));
+sp.success("Loaded!");
+```
+*/
+pub fn set_default_symbols(symbols: Symbols) {
+    *DEFAULT_SYMBOLS
+        .write()
+        .unwrap_or_else(PoisonError::into_inner) = Some(symbols);
+}
+
+/// Whether the locale uses UTF-8, going by the same variables as `setlocale`.
+/// Windows terminals have no locale variables, so there it is assumed.
+fn utf8_locale() -> bool {
+    let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .filter_map(env::var_os)
+        .find(|value| !value.is_empty());
+    match locale {
+        Some(locale) => {
+            let locale = locale.to_string_lossy().to_ascii_lowercase();
+            locale.contains("utf-8") || locale.contains("utf8")
+        }
+        None => cfg!(windows),
+    }
+}
@@ -0,0 +1,330 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::utils::{ansi_len, display_width};
+
+/// Closure backing a custom template key.
+type KeyFn = Arc<dyn Fn() -> String + Send + Sync>;
+
+/**
+Layout of a spinner line.
+
+A template is a string with keys in braces, which are replaced every time the line is drawn.
+The built-in keys are:
+
+* `{spinner}` - The current frame. In the lines printed by the `success` type methods, the symbol.
+* `{msg}` - The message.
+* `{elapsed}` - The time since the spinner was created, e.g. `12.4s`.
+* `{pos}` - The amount of work done so far, see [`Spinner::set_position`](crate::Spinner::set_position).
+* `{total}` - The total amount of work, or nothing if no total is set.
+* `{percent}` - The percentage of work done, without the `%` sign, or nothing if no total is set.
+* `{bar}` - A progress bar such as `#####-----`, or nothing if no total is set.
+
+Keys can be followed by a format spec, like in [`format!`]: `{msg:<20}` pads the message to 20 columns,
+`{msg:>20}` aligns it to the right, `{msg:^20}` centers it, and `{msg:.40}` cuts it off after 40 columns.
+Widths are measured in terminal columns. Use `{{` and `}}` for literal braces.
+Keys that are neither built-in nor registered with [`Template::with_key`] are printed as they are.
+
+# Example
+
+```
+# use spinoff::*;
+# use std::thread::sleep;
+# use std::time::Duration;
+#
+let mut sp = Spinner::new(spinners::Dots, "Uploading...", None);
+sp.set_template("{spinner} {msg:.40} [{elapsed}] {pos}/{total}");
+sp.set_total(3);
+for _ in 0..3 {
+    sleep(Duration::from_millis(300));
+    sp.inc(1);
+}
+sp.success("Uploaded!");
+```
+*/
+#[derive(Clone)]
+pub struct Template {
+    parts: Vec<Part>,
+    keys: HashMap<String, KeyFn>,
+}
+
+#[derive(Clone, Debug)]
+enum Part {
+    Text(String),
+    /// A key, and the text it was parsed from, which is printed if the key is unknown.
+    Key {
+        key: String,
+        spec: Spec,
+        raw: String,
+    },
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct Spec {
+    align: Align,
+    width: Option<usize>,
+    max_width: Option<usize>,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+enum Align {
+    #[default]
+    Left,
+    Right,
+    Center,
+}
+
+impl Template {
+    /// Parse a template. See [`Template`] for the syntax.
+    #[must_use]
+    pub fn new(template: &str) -> Self {
+        let mut parts = Vec::new();
+        let mut text = String::new();
+        let mut rest = template;
+        while let Some(i) = rest.find(['{', '}']) {
+            text.push_str(&rest[..i]);
+            let brace = &rest[i..=i];
+            rest = &rest[i + 1..];
+            // Doubled braces are literal.
+            if let Some(after) = rest.strip_prefix(brace) {
+                text.push_str(brace);
+                rest = after;
+                continue;
+            }
+            // A stray closing brace, or an opening one that is never closed, is kept as text.
+            let end = if brace == "{" { rest.find('}') } else { None };
+            let Some(end) = end else {
+                text.push_str(brace);
+                continue;
+            };
+            if !text.is_empty() {
+                parts.push(Part::Text(std::mem::take(&mut text)));
+            }
+            let (key, spec) = rest[..end].split_once(':').unwrap_or((&rest[..end], ""));
+            parts.push(Part::Key {
+                key: key.trim().to_string(),
+                spec: Spec::parse(spec),
+                raw: format!("{{{}}}", &rest[..end]),
+            });
+            rest = &rest[end + 1..];
+        }
+        text.push_str(rest);
+        if !text.is_empty() {
+            parts.push(Part::Text(text));
+        }
+        Self {
+            parts,
+            keys: HashMap::new(),
+        }
+    }
+
+    /**
+    Register a custom key, whose value is returned by `f` every time the line is drawn.
+    Custom keys take precedence over built-in ones.
+
+    # Example
+
+    ```
+    # use spinoff::*;
+    # use std::thread::sleep;
+    # use std::time::Duration;
+    #
+    let template = Template::new("{spinner} {msg} on {host}").with_key("host", || "build-01".to_string());
+    let mut sp = Spinner::new(spinners::Dots, "Deploying...", None);
+    sp.set_template(template);
+    sleep(Duration::from_millis(800));
+    sp.success("Deployed!");
+    ```
+    */
+    #[must_use]
+    pub fn with_key<F>(mut self, key: &str, f: F) -> Self
+    where
+        F: Fn() -> String + Send + Sync + 'static,
+    {
+        self.keys.insert(key.to_string(), Arc::new(f));
+        self
+    }
+
+    /// Fill in the template, looking up built-in keys with `builtin`.
+    pub(crate) fn render<F>(&self, builtin: F) -> String
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let mut line = String::new();
+        for part in &self.parts {
+            match part {
+                Part::Text(text) => line.push_str(text),
+                Part::Key { key, spec, raw } => {
+                    let value = self.keys.get(key).map(|f| f()).or_else(|| builtin(key));
+                    match value {
+                        Some(value) => line.push_str(&spec.apply(value)),
+                        None => line.push_str(raw),
+                    }
+                }
+            }
+        }
+        line
+    }
+}
+
+impl Spec {
+    /// Parse `[<|>|^][width][.max_width]`. Anything else is ignored.
+    fn parse(spec: &str) -> Self {
+        let (align, spec) = match spec.chars().next() {
+            Some('<') => (Align::Left, &spec[1..]),
+            Some('>') => (Align::Right, &spec[1..]),
+            Some('^') => (Align::Center, &spec[1..]),
+            _ => (Align::Left, spec),
+        };
+        let (width, max_width) = spec.split_once('.').unwrap_or((spec, ""));
+        Self {
+            align,
+            width: width.parse().ok(),
+            max_width: max_width.parse().ok(),
+        }
+    }
+
+    fn apply(self, mut value: String) -> String {
+        if let Some(max_width) = self.max_width {
+            value = truncate(&value, max_width);
+        }
+        let Some(width) = self.width else {
+            return value;
+        };
+        let padding = width.saturating_sub(display_width(&value));
+        let (left, right) = match self.align {
+            Align::Left => (0, padding),
+            Align::Right => (padding, 0),
+            Align::Center => (padding / 2, padding - padding / 2),
+        };
+        format!("{}{value}{}", " ".repeat(left), " ".repeat(right))
+    }
+}
+
+/// Cut `value` off after `max_width` columns, without splitting graphemes.
+fn truncate(value: &str, max_width: usize) -> String {
+    let mut truncated = String::with_capacity(value.len());
+    let mut width = 0;
+    let mut rest = value;
+    while !rest.is_empty() {
+        let (text, escapes) = rest.split_at(rest.find('\x1b').unwrap_or(rest.len()));
+        for grapheme in text.graphemes(true) {
+            width += grapheme.width();
+            if width <= max_width {
+                truncated.push_str(grapheme);
+            }
+        }
+        // Escape sequences take up no space, and are kept so that styles are still reset.
+        let escape = ansi_len(escapes);
+        truncated.push_str(&escapes[..escape]);
+        rest = &escapes[escape..];
+    }
+    truncated
+}
+
+impl From<&str> for Template {
+    fn from(template: &str) -> Self {
+        Self::new(template)
+    }
+}
+
+impl From<String> for Template {
+    fn from(template: String) -> Self {
+        Self::new(&template)
+    }
+}
+
+impl fmt::Debug for Template {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Template")
+            .field("parts", &self.parts)
+            .field("keys", &self.keys.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(template: &str, msg: &str) -> String {
+        Template::new(template).render(|key| match key {
+            "spinner" => Some("⠋".to_string()),
+            "msg" => Some(msg.to_string()),
+            _ => None,
+        })
+    }
+
+    #[test]
+    fn keys_are_replaced() {
+        assert_eq!(render("{spinner} {msg}", "Loading"), "⠋ Loading");
+        assert_eq!(render("[{ msg }]", "Loading"), "[Loading]");
+        assert_eq!(render("no keys", "Loading"), "no keys");
+    }
+
+    #[test]
+    fn braces_are_escaped() {
+        assert_eq!(render("{{{msg}}}", "Loading"), "{Loading}");
+        assert_eq!(render("{{msg}}", "Loading"), "{msg}");
+        assert_eq!(render("a } b", "Loading"), "a } b");
+    }
+
+    #[test]
+    fn unclosed_braces_are_text() {
+        assert_eq!(render("{msg} {spinner", "Loading"), "Loading {spinner");
+        assert_eq!(render("{", "Loading"), "{");
+    }
+
+    #[test]
+    fn unknown_keys_are_kept() {
+        assert_eq!(render("{msg} {host}", "Loading"), "Loading {host}");
+        assert_eq!(render("{host:>10.3}", "Loading"), "{host:>10.3}");
+    }
+
+    #[test]
+    fn custom_keys_take_precedence() {
+        let template = Template::new("{host} {msg}").with_key("msg", || "custom".to_string());
+        let template = template.with_key("host", || "build-01".to_string());
+        assert_eq!(
+            template.render(|_| Some("builtin".to_string())),
+            "build-01 custom"
+        );
+    }
+
+    #[test]
+    fn values_are_aligned() {
+        assert_eq!(render("[{msg:8}]", "abc"), "[abc     ]");
+        assert_eq!(render("[{msg:<8}]", "abc"), "[abc     ]");
+        assert_eq!(render("[{msg:>8}]", "abc"), "[     abc]");
+        assert_eq!(render("[{msg:^8}]", "abc"), "[  abc   ]");
+        assert_eq!(render("[{msg:>2}]", "abc"), "[abc]");
+        // Widths are measured in columns.
+        assert_eq!(render("[{msg:>6}]", "漢字"), "[  漢字]");
+    }
+
+    #[test]
+    fn values_are_truncated() {
+        assert_eq!(render("[{msg:.4}]", "Loading"), "[Load]");
+        assert_eq!(render("[{msg:.10}]", "Loading"), "[Loading]");
+        assert_eq!(render("[{msg:>6.3}]", "Loading"), "[   Loa]");
+        // Wide graphemes are not split.
+        assert_eq!(render("[{msg:.3}]", "漢字"), "[漢]");
+        assert_eq!(render("[{msg:.1}]", "e\u{301}x"), "[e\u{301}]");
+    }
+
+    #[test]
+    fn truncation_keeps_ansi_escapes() {
+        let styled = "\x1b[1;31mLoading\x1b[0m";
+        assert_eq!(render("{msg:.4}", styled), "\x1b[1;31mLoad\x1b[0m");
+        assert_eq!(
+            render("{msg:.3}", "a\x1b[32mbcd\x1b[0me"),
+            "a\x1b[32mbc\x1b[0m"
+        );
+        // Padding only counts visible columns.
+        assert_eq!(render("[{msg:>6.4}]", styled), "[  \x1b[1;31mLoad\x1b[0m]");
+    }
+}
@@ -1,7 +1,20 @@
-use crate::Streams;
-use colored::{ColoredString, Colorize};
+use crate::Output;
+use std::borrow::Cow;
+use std::time::Duration;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+use std::sync::{Mutex, MutexGuard, PoisonError};
 
-/// Color for spinner. Supports the 8 basic colors and a custom color variant.
+/// Ticks it takes a gradient to go from one color to the other.
+const GRADIENT_STEPS: u32 = 12;
+/// Degrees the hue of a rainbow moves every tick.
+const RAINBOW_STEP: u32 = 10;
+/// Ticks it takes a pulse to go from full brightness to dim.
+const PULSE_STEPS: u32 = 8;
+
+/// Color for spinner. Supports the 8 basic colors, their bright variants,
+/// the 256 color ANSI palette and a custom color variant.
+/// The animated variants change color every frame.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 #[non_exhaustive]
 pub enum Color {
@@ -14,31 +27,234 @@ pub enum Color {
     Black,
     Magenta,
     TrueColor { r: u8, g: u8, b: u8 },
+    BrightBlue,
+    BrightGreen,
+    BrightRed,
+    BrightYellow,
+    BrightCyan,
+    BrightWhite,
+    BrightBlack,
+    BrightMagenta,
+    /// A color of the 256 color ANSI palette.
+    Ansi256(u8),
+    /// Fades from one RGB color to the other and back.
+    Gradient { from: (u8, u8, u8), to: (u8, u8, u8) },
+    /// Cycles through the colors of the rainbow.
+    Rainbow,
+    /// Fades an RGB color to dim and back.
+    Pulse { r: u8, g: u8, b: u8 },
 }
 
-pub fn colorize(color: Option<Color>, frame: &str) -> ColoredString {
-    match color {
-        Some(Color::Blue) => frame.blue(),
-        Some(Color::Green) => frame.green(),
-        Some(Color::Red) => frame.red(),
-        Some(Color::Yellow) => frame.yellow(),
-        Some(Color::Cyan) => frame.cyan(),
-        Some(Color::White) => frame.white(),
-        Some(Color::Black) => frame.black(),
-        Some(Color::Magenta) => frame.magenta(),
-        Some(Color::TrueColor { r, g, b }) => frame.truecolor(r, g, b),
-        None => frame.normal()
+impl Color {
+    /// The SGR parameters selecting this color, as a foreground or a background color.
+    pub(crate) fn sgr(self, background: bool) -> String {
+        let base = if background { 40 } else { 30 };
+        let basic = |offset: u8| (base + offset).to_string();
+        let extended = if background { 48 } else { 38 };
+        match self {
+            Self::Black => basic(0),
+            Self::Red => basic(1),
+            Self::Green => basic(2),
+            Self::Yellow => basic(3),
+            Self::Blue => basic(4),
+            Self::Magenta => basic(5),
+            Self::Cyan => basic(6),
+            Self::White => basic(7),
+            Self::BrightBlack => basic(60),
+            Self::BrightRed => basic(61),
+            Self::BrightGreen => basic(62),
+            Self::BrightYellow => basic(63),
+            Self::BrightBlue => basic(64),
+            Self::BrightMagenta => basic(65),
+            Self::BrightCyan => basic(66),
+            Self::BrightWhite => basic(67),
+            Self::Ansi256(code) => format!("{extended};5;{code}"),
+            Self::TrueColor { r, g, b } => format!("{extended};2;{r};{g};{b}"),
+            Self::Gradient { .. } | Self::Rainbow | Self::Pulse { .. } => self.at(0).sgr(background),
+        }
+    }
+
+    /// The color shown at frame number `tick`. Only animated colors change.
+    pub(crate) fn at(self, tick: u64) -> Self {
+        match self {
+            Self::Gradient { from, to } => {
+                let step = triangle(tick, GRADIENT_STEPS);
+                let mix = |from: u8, to: u8| {
+                    let (from, to) = (u32::from(from), u32::from(to));
+                    // Weighted average, so it stays within `0..=255`.
+                    channel((from * (GRADIENT_STEPS - step) + to * step) / GRADIENT_STEPS)
+                };
+                Self::TrueColor {
+                    r: mix(from.0, to.0),
+                    g: mix(from.1, to.1),
+                    b: mix(from.2, to.2),
+                }
+            }
+            Self::Rainbow => {
+                let hue = u32::try_from(tick % 36).unwrap_or(0) * RAINBOW_STEP;
+                let rising = channel(hue % 60 * 255 / 60);
+                let falling = 255 - rising;
+                let (r, g, b) = match hue / 60 {
+                    0 => (255, rising, 0),
+                    1 => (falling, 255, 0),
+                    2 => (0, 255, rising),
+                    3 => (0, falling, 255),
+                    4 => (rising, 0, 255),
+                    _ => (255, 0, falling),
+                };
+                Self::TrueColor { r, g, b }
+            }
+            Self::Pulse { r, g, b } => {
+                // Between 100% and 30% brightness.
+                let percent = 100 - 70 * triangle(tick, PULSE_STEPS) / PULSE_STEPS;
+                let dim = |value: u8| channel(u32::from(value) * percent / 100);
+                Self::TrueColor {
+                    r: dim(r),
+                    g: dim(g),
+                    b: dim(b),
+                }
+            }
+            _ => self,
+        }
     }
 }
 
+/// Goes from 0 up to `steps` and back down, one step per tick.
+fn triangle(tick: u64, steps: u32) -> u32 {
+    let step = u32::try_from(tick % u64::from(2 * steps)).unwrap_or(0);
+    if step <= steps {
+        step
+    } else {
+        2 * steps - step
+    }
+}
+
+fn channel(value: u32) -> u8 {
+    u8::try_from(value).unwrap_or(u8::MAX)
+}
+
 /// Internal function for deleting the last line in a terminal.
 /// This is used to clear the spinner.
-pub fn delete_last_line(clear_length: usize, stream: Streams) {
+/// `clear_width` is the display width of the line, as returned by [`display_width`].
+pub fn delete_last_line(clear_width: usize, stream: &Output) {
+    let rows = wrapped_rows(clear_width, stream.columns());
+    if rows > 1 {
+        // The line wrapped, so go back up to its first row and clear everything below it.
+        write!(stream, "\r\x1b[{}A\x1b[J", rows - 1);
+        return;
+    }
     write!(stream, "\r");
-    for _ in 0..clear_length {
+    for _ in 0..clear_width {
         write!(stream, " ");
     }
     write!(stream, "\r");
 }
 
+/// Format a duration the way the elapsed time is shown next to a spinner, e.g. `12.4s` or `3m 05s`.
+pub fn format_elapsed(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    match secs {
+        0..=59 => format!("{:.1}s", elapsed.as_secs_f64()),
+        60..=3599 => format!("{}m {:02}s", secs / 60, secs % 60),
+        _ => format!("{}h {:02}m", secs / 3600, secs % 3600 / 60),
+    }
+}
+
+/// Number of terminal columns `text` takes up.
+/// ANSI escape sequences take up no space, and every grapheme takes up its East-Asian width.
+pub fn display_width(text: &str) -> usize {
+    strip_ansi(text)
+        .graphemes(true)
+        .map(UnicodeWidthStr::width)
+        .sum()
+}
+
+/// Number of terminal rows a line `width` columns wide takes up on a terminal `columns` wide.
+pub fn wrapped_rows(width: usize, columns: Option<usize>) -> usize {
+    match columns {
+        Some(columns) if columns > 0 => width.div_ceil(columns).max(1),
+        _ => 1,
+    }
+}
+
+/// Remove the ANSI escape sequences used for colors and cursor movement from `text`.
+fn strip_ansi(text: &str) -> Cow<'_, str> {
+    if !text.contains('\x1b') {
+        return Cow::Borrowed(text);
+    }
+    let mut stripped = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('\x1b') {
+        stripped.push_str(&rest[..start]);
+        rest = &rest[start + ansi_len(&rest[start..])..];
+    }
+    stripped.push_str(rest);
+    Cow::Owned(stripped)
+}
+
+/// Length in bytes of the ANSI escape sequence `text` starts with, or 0 if it doesn't start with one.
+pub fn ansi_len(text: &str) -> usize {
+    let mut chars = text.char_indices();
+    match (chars.next(), chars.next()) {
+        // A control sequence ends with its first character in the `@` to `~` range.
+        (Some((_, '\x1b')), Some((_, '['))) => chars
+            .find(|(_, c)| ('@'..='~').contains(c))
+            .map_or(text.len(), |(end, c)| end + c.len_utf8()),
+        (Some((_, '\x1b')), Some((end, c))) => end + c.len_utf8(),
+        (Some((_, '\x1b')), None) => 1,
+        _ => 0,
+    }
+}
+
+/// Lock a mutex, recovering the data if a thread panicked while holding the lock.
+/// A half-drawn spinner is still better than a second panic.
+pub fn lock<T: ?Sized>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_width_counts_columns() {
+        assert_eq!(display_width("Loading"), 7);
+        assert_eq!(display_width("🍕 pizza"), 8);
+        assert_eq!(display_width("漢字"), 4);
+        assert_eq!(display_width("e\u{301}"), 1);
+        assert_eq!(display_width("👨\u{200d}👩\u{200d}👧"), 2);
+    }
+
+    #[test]
+    fn display_width_ignores_ansi_escapes() {
+        assert_eq!(display_width("\x1b[1;38;5;208m漢字\x1b[0m ok"), 7);
+        assert_eq!(display_width("\x1b[2A\x1b[J"), 0);
+    }
+
+    #[test]
+    fn strip_ansi_removes_escapes() {
+        assert!(matches!(strip_ansi("plain"), Cow::Borrowed("plain")));
+        assert_eq!(strip_ansi("\x1b[31mred\x1b[0m"), "red");
+        assert_eq!(strip_ansi("a\x1b[38;2;255;128;0mb\x1b[0m\x1bc"), "ab");
+        // An unfinished sequence runs to the end of the text.
+        assert_eq!(strip_ansi("done\x1b[3"), "done");
+        assert_eq!(strip_ansi("\x1b"), "");
+    }
 
+    #[test]
+    fn wrapped_rows_counts_rows() {
+        assert_eq!(wrapped_rows(0, Some(80)), 1);
+        assert_eq!(wrapped_rows(80, Some(80)), 1);
+        assert_eq!(wrapped_rows(81, Some(80)), 2);
+        assert_eq!(wrapped_rows(240, Some(80)), 3);
+        assert_eq!(wrapped_rows(500, Some(0)), 1);
+        assert_eq!(wrapped_rows(500, None), 1);
+    }
+
+    #[test]
+    fn format_elapsed_picks_units() {
+        assert_eq!(format_elapsed(Duration::from_millis(12_400)), "12.4s");
+        assert_eq!(format_elapsed(Duration::from_secs(185)), "3m 05s");
+        assert_eq!(format_elapsed(Duration::from_secs(3 * 3600 + 7 * 60)), "3h 07m");
+    }
+}